@@ -0,0 +1,93 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use oid_str::{AbsoluteOidVec, SmallAbsoluteOid};
+
+// typical certificate oids: attribute types, extensions, algorithms
+const OIDS: &[&str] = &[
+    "2.5.4.3",
+    "2.5.29.15",
+    "1.2.840.113549.1.1.11",
+    "1.2.840.10045.4.3.2",
+    "1.3.6.1.5.5.7.1.1",
+    "1.3.6.1.4.1.11129.2.4.2",
+];
+
+fn encoded() -> Vec<AbsoluteOidVec> {
+    OIDS.iter().map(|s| s.parse().unwrap()).collect()
+}
+
+fn from_oid(c: &mut Criterion) {
+    let oids = encoded();
+    let mut group = c.benchmark_group("from_oid");
+    group.bench_function("AbsoluteOidVec", |b| {
+        b.iter(|| {
+            for oid in &oids {
+                black_box(AbsoluteOidVec::from_oid(black_box(oid)));
+            }
+        })
+    });
+    group.bench_function("SmallAbsoluteOid", |b| {
+        b.iter(|| {
+            for oid in &oids {
+                black_box(SmallAbsoluteOid::from_oid(black_box(oid)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    group.bench_function("AbsoluteOidVec", |b| {
+        b.iter(|| {
+            for s in OIDS {
+                black_box(black_box(s).parse::<AbsoluteOidVec>().unwrap());
+            }
+        })
+    });
+    group.bench_function("SmallAbsoluteOid", |b| {
+        b.iter(|| {
+            for s in OIDS {
+                black_box(black_box(s).parse::<SmallAbsoluteOid>().unwrap());
+            }
+        })
+    });
+    group.finish();
+}
+
+fn clone(c: &mut Criterion) {
+    let vecs = encoded();
+    let smalls: Vec<SmallAbsoluteOid> = vecs.iter().map(|oid| SmallAbsoluteOid::from_oid(oid)).collect();
+    let mut group = c.benchmark_group("clone");
+    group.bench_function("AbsoluteOidVec", |b| b.iter(|| black_box(&vecs).clone()));
+    group.bench_function("SmallAbsoluteOid", |b| b.iter(|| black_box(&smalls).clone()));
+    group.finish();
+}
+
+#[cfg(feature = "der")]
+fn der_decode(c: &mut Criterion) {
+    use der::{Decode, Encode};
+
+    let encoded: Vec<Vec<u8>> = encoded().iter().map(|oid| oid.to_der().unwrap()).collect();
+    let mut group = c.benchmark_group("der_decode");
+    group.bench_function("AbsoluteOidVec", |b| {
+        b.iter(|| {
+            for der in &encoded {
+                black_box(AbsoluteOidVec::from_der(black_box(der)).unwrap());
+            }
+        })
+    });
+    group.bench_function("SmallAbsoluteOid", |b| {
+        b.iter(|| {
+            for der in &encoded {
+                black_box(SmallAbsoluteOid::from_der(black_box(der)).unwrap());
+            }
+        })
+    });
+    group.finish();
+}
+
+#[cfg(not(feature = "der"))]
+criterion_group!(benches, from_oid, parse, clone);
+#[cfg(feature = "der")]
+criterion_group!(benches, from_oid, parse, clone, der_decode);
+criterion_main!(benches);
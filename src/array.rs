@@ -0,0 +1,194 @@
+//! Owned oids of fixed capacity, that don't need `alloc`
+
+use crate::{
+    encode::{b128_len, write_b128},
+    AbsoluteOid, Arc, BufferTooSmallError, RelativeOid, RootOid, ARC_LEN,
+};
+
+/// [RelativeOid] of up to `N` encoded bytes, stored inline
+///
+/// Operations, that would exceed the capacity, fail with [BufferTooSmallError]
+/// and leave the oid unchanged.
+/// ```
+/// # use oid_str::RelativeOidArray;
+/// let mut oid = RelativeOidArray::<4>::new();
+/// oid.push(1).unwrap();
+/// oid.push(840).unwrap();
+/// assert_eq!(oid.to_string(), ".1.840");
+/// assert_eq!(oid.push(113549).unwrap_err().required, 6);
+/// assert_eq!(oid.pop(), Some(840));
+/// ```
+#[derive(Clone, Copy)]
+pub struct RelativeOidArray<const N: usize> {
+    len: usize,
+    bytes: [u8; N],
+}
+
+impl<const N: usize> Default for RelativeOidArray<N> {
+    fn default() -> Self {
+        RelativeOidArray::new()
+    }
+}
+
+impl<const N: usize> core::ops::Deref for RelativeOidArray<N> {
+    type Target = RelativeOid;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_oid()
+    }
+}
+
+impl<const N: usize> core::ops::DerefMut for RelativeOidArray<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_oid()
+    }
+}
+
+impl<const N: usize> RelativeOidArray<N> {
+    /// Empty oid
+    pub const fn new() -> RelativeOidArray<N> {
+        RelativeOidArray { len: 0, bytes: [0; N] }
+    }
+
+    pub fn from_oid(oid: &RelativeOid) -> Result<RelativeOidArray<N>, BufferTooSmallError> {
+        let mut array = RelativeOidArray::new();
+        array.extend(oid)?;
+        Ok(array)
+    }
+
+    /// Maximum number of encoded bytes
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub fn as_oid(&self) -> &RelativeOid {
+        // SAFETY: we only store valid RelativeOid bytes
+        unsafe { RelativeOid::from_bytes_unchecked(&self.bytes[..self.len]) }
+    }
+
+    pub fn as_mut_oid(&mut self) -> &mut RelativeOid {
+        // SAFETY: we only store valid RelativeOid bytes
+        unsafe { RelativeOid::from_mut_bytes_unchecked(&mut self.bytes[..self.len]) }
+    }
+
+    pub fn push(&mut self, arc: Arc) -> Result<(), BufferTooSmallError> {
+        let mut buffer = [0u8; ARC_LEN];
+        let arc = write_b128(&mut buffer, arc);
+        self.extend(arc)
+    }
+
+    pub fn extend(&mut self, oid: &RelativeOid) -> Result<(), BufferTooSmallError> {
+        self.len = append(&mut self.bytes, self.len, oid)?;
+        Ok(())
+    }
+
+    /// Removes the last arc
+    pub fn pop(&mut self) -> Option<Arc> {
+        let arc = self.as_oid().arcs().last()?;
+        self.len -= b128_len(arc);
+        Some(arc)
+    }
+}
+
+/// [AbsoluteOid] of up to `N` encoded bytes, stored inline
+///
+/// Operations, that would exceed the capacity, fail with [BufferTooSmallError]
+/// and leave the oid unchanged.
+/// ```
+/// # use oid_str::AbsoluteOidArray;
+/// let mut oid: AbsoluteOidArray<8> = "1.2.840".parse().unwrap();
+/// oid.push(113549).unwrap();
+/// assert_eq!(oid.to_string(), "1.2.840.113549");
+/// assert_eq!(oid.pop(), Some(113549));
+/// assert_eq!(oid.pop(), Some(840));
+/// // the root stays
+/// assert_eq!(oid.pop(), None);
+/// ```
+#[derive(Clone, Copy)]
+pub struct AbsoluteOidArray<const N: usize> {
+    len: usize,
+    bytes: [u8; N],
+}
+
+impl<const N: usize> core::ops::Deref for AbsoluteOidArray<N> {
+    type Target = AbsoluteOid;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_oid()
+    }
+}
+
+impl<const N: usize> core::ops::DerefMut for AbsoluteOidArray<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_oid()
+    }
+}
+
+impl<const N: usize> AbsoluteOidArray<N> {
+    pub fn from_root(root: RootOid) -> Result<AbsoluteOidArray<N>, BufferTooSmallError> {
+        AbsoluteOidArray::from_oid(root.as_absolute())
+    }
+
+    pub fn from_oid(oid: &AbsoluteOid) -> Result<AbsoluteOidArray<N>, BufferTooSmallError> {
+        let mut bytes = [0; N];
+        // SAFETY: an absolute oid is a valid relative one
+        let relative = unsafe { RelativeOid::from_bytes_unchecked(oid.as_bytes()) };
+        let len = append(&mut bytes, 0, relative)?;
+        Ok(AbsoluteOidArray { len, bytes })
+    }
+
+    /// Maximum number of encoded bytes
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub fn as_oid(&self) -> &AbsoluteOid {
+        // SAFETY: we only store valid contents
+        unsafe { AbsoluteOid::from_bytes_unchecked(&self.bytes[..self.len]) }
+    }
+
+    pub fn as_mut_oid(&mut self) -> &mut AbsoluteOid {
+        // SAFETY: we only store valid contents
+        unsafe { AbsoluteOid::from_mut_bytes_unchecked(&mut self.bytes[..self.len]) }
+    }
+
+    pub fn push(&mut self, arc: Arc) -> Result<(), BufferTooSmallError> {
+        let mut buffer = [0u8; ARC_LEN];
+        let arc = write_b128(&mut buffer, arc);
+        self.extend(arc)
+    }
+
+    pub fn extend(&mut self, oid: &RelativeOid) -> Result<(), BufferTooSmallError> {
+        self.len = append(&mut self.bytes, self.len, oid)?;
+        Ok(())
+    }
+
+    /// Removes the last arc, the root is never removed
+    pub fn pop(&mut self) -> Option<Arc> {
+        let arc = self.as_oid().tail().arcs().last()?;
+        self.len -= b128_len(arc);
+        Some(arc)
+    }
+
+    pub(crate) fn parse(s: &str) -> Result<AbsoluteOidArray<N>, crate::ParseIntoError> {
+        let mut bytes = [0; N];
+        let len = AbsoluteOid::parse_into(s, &mut bytes)?.as_bytes().len();
+        Ok(AbsoluteOidArray { len, bytes })
+    }
+}
+
+impl<const N: usize> RelativeOidArray<N> {
+    pub(crate) fn parse(s: &str) -> Result<RelativeOidArray<N>, crate::ParseIntoError> {
+        let mut bytes = [0; N];
+        let len = RelativeOid::parse_into(s, &mut bytes)?.as_bytes().len();
+        Ok(RelativeOidArray { len, bytes })
+    }
+}
+
+/// Copies `oid` after the first `len` bytes, returns the new length
+fn append(bytes: &mut [u8], len: usize, oid: &RelativeOid) -> Result<usize, BufferTooSmallError> {
+    let required = len + oid.as_bytes().len();
+    let dest = bytes.get_mut(len..required).ok_or(BufferTooSmallError { required })?;
+    dest.copy_from_slice(oid.as_bytes());
+    Ok(required)
+}
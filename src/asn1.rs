@@ -0,0 +1,370 @@
+//! Parsing of ASN.1 value notation, e.g. `{ iso(1) identified-organization(3) dod(6) 1 }`
+//!
+//! Components may be a number (`6`), a name and a number (`dod(6)`),
+//! or a bare name of a well-known arc (`iso`).
+//! The first component may also refer to another oid (`{ id-pkix 3 }`),
+//! such references are looked up with an [OidResolver].
+
+use core::{fmt, ops::Range};
+
+use crate::AbsoluteOid;
+#[cfg(feature = "alloc")]
+use crate::{Arc, Arc0};
+
+/// Source of oids for symbolic references in ASN.1 value notation
+pub trait OidResolver {
+    fn resolve(&self, name: &str) -> Option<&AbsoluteOid>;
+}
+
+impl<T: OidResolver + ?Sized> OidResolver for &T {
+    fn resolve(&self, name: &str) -> Option<&AbsoluteOid> {
+        (**self).resolve(name)
+    }
+}
+
+/// Resolves nothing, used when references are not expected
+impl OidResolver for () {
+    fn resolve(&self, _name: &str) -> Option<&AbsoluteOid> {
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Asn1ParseError {
+    pub kind: Asn1ErrorKind,
+    /// Bytes of the input, that caused the error
+    pub span: Range<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Asn1ErrorKind {
+    /// Input does not start with `{`
+    ExpectedOpenBrace,
+    /// Input ends before the closing `}`
+    Unterminated,
+    UnexpectedChar,
+    /// Something follows the closing `}`
+    TrailingInput,
+    /// Number has leading zeros
+    LeadingZero,
+    /// Number does not fit into [Arc]
+    OutOfRange,
+    /// Identifier must start with a lowercase letter and must not end with, or contain two `-`
+    InvalidName,
+    /// Name of a well-known arc does not match the number, e.g. `iso(2)`
+    NameMismatch,
+    /// Bare name is neither a well-known arc, nor a resolved reference
+    UnknownName,
+    /// First two arcs do not form a valid root
+    InvalidRoot,
+    /// Less than two arcs
+    TooFewArcs,
+}
+
+impl Asn1ParseError {
+    #[cfg(feature = "alloc")]
+    fn new(kind: Asn1ErrorKind, span: Range<usize>) -> Asn1ParseError {
+        Asn1ParseError { kind, span }
+    }
+}
+
+impl fmt::Display for Asn1ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.span.start, self.span.end)
+    }
+}
+
+impl crate::error::Error for Asn1ParseError {}
+
+impl fmt::Display for Asn1ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Asn1ErrorKind::ExpectedOpenBrace => "expected `{`",
+            Asn1ErrorKind::Unterminated => "expected `}`",
+            Asn1ErrorKind::UnexpectedChar => "unexpected character",
+            Asn1ErrorKind::TrailingInput => "unexpected input after `}`",
+            Asn1ErrorKind::LeadingZero => "number with leading zeros",
+            Asn1ErrorKind::OutOfRange => "arc is out of range",
+            Asn1ErrorKind::InvalidName => "invalid identifier",
+            Asn1ErrorKind::NameMismatch => "name does not match the arc",
+            Asn1ErrorKind::UnknownName => "unknown name",
+            Asn1ErrorKind::InvalidRoot => "invalid first two arcs",
+            Asn1ErrorKind::TooFewArcs => "absolute oid must have at least two arcs",
+        })
+    }
+}
+
+/// Single component between the braces
+#[cfg(feature = "alloc")]
+pub(crate) struct Component<'s> {
+    pub(crate) name: Option<&'s str>,
+    pub(crate) number: Option<Arc>,
+    pub(crate) span: Range<usize>,
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) struct Parser<'s> {
+    s: &'s str,
+    pos: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<'s> Parser<'s> {
+    pub(crate) fn new(s: &'s str) -> Parser<'s> {
+        Parser { s, pos: 0 }
+    }
+
+    pub(crate) fn open(&mut self) -> Result<(), Asn1ParseError> {
+        self.skip_whitespace();
+        if self.peek() != Some(b'{') {
+            return Err(self.error_here(Asn1ErrorKind::ExpectedOpenBrace));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    /// Next component, or `None` after the closing brace
+    pub(crate) fn component(&mut self) -> Result<Option<Component<'s>>, Asn1ParseError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        match self.peek() {
+            Some(b'}') => {
+                self.pos += 1;
+                return Ok(None);
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let number = self.number()?;
+                return Ok(Some(Component {
+                    name: None,
+                    number: Some(number),
+                    span: start..self.pos,
+                }));
+            }
+            Some(c) if c.is_ascii_alphabetic() => {}
+            _ => return Err(self.error_here(Asn1ErrorKind::UnexpectedChar)),
+        }
+
+        let name = self.identifier()?;
+        let end = self.pos;
+        self.skip_whitespace();
+        if self.peek() != Some(b'(') {
+            return Ok(Some(Component {
+                name: Some(name),
+                number: None,
+                span: start..end,
+            }));
+        }
+        self.pos += 1;
+        self.skip_whitespace();
+        if !self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            return Err(self.error_here(Asn1ErrorKind::UnexpectedChar));
+        }
+        let number = self.number()?;
+        self.skip_whitespace();
+        if self.peek() != Some(b')') {
+            return Err(self.error_here(Asn1ErrorKind::UnexpectedChar));
+        }
+        self.pos += 1;
+        Ok(Some(Component {
+            name: Some(name),
+            number: Some(number),
+            span: start..self.pos,
+        }))
+    }
+
+    pub(crate) fn close(&mut self) -> Result<(), Asn1ParseError> {
+        self.skip_whitespace();
+        if self.pos != self.s.len() {
+            return Err(Asn1ParseError::new(Asn1ErrorKind::TrailingInput, self.pos..self.s.len()));
+        }
+        Ok(())
+    }
+
+    fn number(&mut self) -> Result<Arc, Asn1ParseError> {
+        let start = self.pos;
+        self.skip_while(|c| c.is_ascii_digit());
+        let digits = &self.s[start..self.pos];
+        if digits.len() > 1 && digits.starts_with('0') {
+            return Err(Asn1ParseError::new(Asn1ErrorKind::LeadingZero, start..self.pos));
+        }
+        digits
+            .parse()
+            .map_err(|_| Asn1ParseError::new(Asn1ErrorKind::OutOfRange, start..self.pos))
+    }
+
+    fn identifier(&mut self) -> Result<&'s str, Asn1ParseError> {
+        let start = self.pos;
+        self.skip_while(|c| c.is_ascii_alphanumeric() || c == b'-');
+        let name = &self.s[start..self.pos];
+        let valid = name.as_bytes()[0].is_ascii_lowercase() && !name.ends_with('-') && !name.contains("--");
+        if !valid {
+            return Err(Asn1ParseError::new(Asn1ErrorKind::InvalidName, start..self.pos));
+        }
+        Ok(name)
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.skip_while(|c| c.is_ascii_whitespace());
+    }
+
+    fn skip_while(&mut self, f: impl Fn(u8) -> bool) {
+        while self.peek().is_some_and(&f) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.s.as_bytes().get(self.pos).copied()
+    }
+
+    /// Error at the current character, or at the end of input
+    fn error_here(&self, kind: Asn1ErrorKind) -> Asn1ParseError {
+        match self.s[self.pos..].chars().next() {
+            Some(c) => Asn1ParseError::new(kind, self.pos..self.pos + c.len_utf8()),
+            None if matches!(kind, Asn1ErrorKind::UnexpectedChar) => {
+                Asn1ParseError::new(Asn1ErrorKind::Unterminated, self.pos..self.pos)
+            }
+            None => Asn1ParseError::new(kind, self.pos..self.pos),
+        }
+    }
+}
+
+/// Arcs with names fixed by X.680, `arc0` is the parent of the arc, if it is the second one
+#[cfg(feature = "alloc")]
+pub(crate) fn well_known(arc0: Option<Arc0>, name: &str) -> Option<Arc> {
+    let arc = match (arc0, name) {
+        (None, "itu-t") | (None, "ccitt") => 0,
+        (None, "iso") => 1,
+        (None, "joint-iso-itu-t") | (None, "joint-iso-ccitt") => 2,
+        (Some(Arc0::ItuT), "recommendation") => 0,
+        (Some(Arc0::ItuT), "question") => 1,
+        (Some(Arc0::ItuT), "administration") => 2,
+        (Some(Arc0::ItuT), "network-operator") => 3,
+        (Some(Arc0::ItuT), "identified-organization") => 4,
+        (Some(Arc0::ItuT), "r-recommendation") => 5,
+        (Some(Arc0::ItuT), "data") => 9,
+        (Some(Arc0::Iso), "standard") => 0,
+        (Some(Arc0::Iso), "registration-authority") => 1,
+        (Some(Arc0::Iso), "member-body") => 2,
+        (Some(Arc0::Iso), "identified-organization") => 3,
+        _ => return None,
+    };
+    Some(arc)
+}
+
+#[cfg(feature = "alloc")]
+mod owned {
+    use alloc::collections::BTreeMap;
+    use core::{borrow::Borrow, convert::TryFrom, ops::Range};
+
+    use super::{well_known, Asn1ErrorKind, Asn1ParseError, OidResolver, Parser};
+    use crate::{AbsoluteOid, AbsoluteOidVec, Arc, Arc0, Arc1, RootOid};
+
+    impl<K: Borrow<str> + Ord, V: AsRef<AbsoluteOid>> OidResolver for BTreeMap<K, V> {
+        fn resolve(&self, name: &str) -> Option<&AbsoluteOid> {
+            self.get(name).map(AsRef::as_ref)
+        }
+    }
+
+    impl AbsoluteOidVec {
+        /// Parses ASN.1 value notation, without references to other oids
+        /// ```
+        /// # use oid_str::AbsoluteOidVec;
+        /// let oid = AbsoluteOidVec::parse_asn1("{ iso(1) identified-organization(3) dod(6) 1 }").unwrap();
+        /// assert_eq!(oid.to_string(), "1.3.6.1");
+        ///
+        /// let err = AbsoluteOidVec::parse_asn1("{ iso(2) 3 }").unwrap_err();
+        /// assert_eq!(err.span, 2..8);
+        /// ```
+        pub fn parse_asn1(s: &str) -> Result<AbsoluteOidVec, Asn1ParseError> {
+            AbsoluteOidVec::parse_asn1_with(s, &())
+        }
+
+        /// Parses ASN.1 value notation, the first component may be a reference resolved with `resolver`
+        /// ```
+        /// # use std::collections::BTreeMap;
+        /// # use oid_str::AbsoluteOidVec;
+        /// let mut defs = BTreeMap::new();
+        /// defs.insert("id-pkix", "1.3.6.1.5.5.7".parse::<AbsoluteOidVec>().unwrap());
+        ///
+        /// let oid = AbsoluteOidVec::parse_asn1_with("{ id-pkix id-kp(3) }", &defs).unwrap();
+        /// assert_eq!(oid.to_string(), "1.3.6.1.5.5.7.3");
+        /// ```
+        pub fn parse_asn1_with<R: OidResolver + ?Sized>(
+            s: &str,
+            resolver: &R,
+        ) -> Result<AbsoluteOidVec, Asn1ParseError> {
+            let mut parser = Parser::new(s);
+            parser.open()?;
+            let mut builder = Builder::Empty;
+            while let Some(component) = parser.component()? {
+                let known = component.name.and_then(|name| builder.well_known(name));
+                let arc = match (component.name, component.number) {
+                    (Some(_), Some(number)) => {
+                        let fixed_names = matches!(builder, Builder::Empty);
+                        if known.map_or(fixed_names, |arc| arc != number) {
+                            return Err(Asn1ParseError::new(Asn1ErrorKind::NameMismatch, component.span));
+                        }
+                        number
+                    }
+                    (None, Some(number)) => number,
+                    (Some(name), None) => match (known, &builder) {
+                        (Some(arc), _) => arc,
+                        (None, Builder::Empty) => {
+                            let oid = resolver
+                                .resolve(name)
+                                .ok_or_else(|| Asn1ParseError::new(Asn1ErrorKind::UnknownName, component.span))?;
+                            builder = Builder::Oid(AbsoluteOidVec::from_oid(oid));
+                            continue;
+                        }
+                        (None, _) => {
+                            return Err(Asn1ParseError::new(Asn1ErrorKind::UnknownName, component.span));
+                        }
+                    },
+                    (None, None) => unreachable!("component without name and number"),
+                };
+                builder.push(arc, component.span)?;
+            }
+            parser.close()?;
+            match builder {
+                Builder::Oid(oid) => Ok(oid),
+                _ => Err(Asn1ParseError::new(Asn1ErrorKind::TooFewArcs, 0..s.len())),
+            }
+        }
+    }
+
+    /// Arcs collected so far
+    enum Builder {
+        Empty,
+        Arc0(Arc0),
+        Oid(AbsoluteOidVec),
+    }
+
+    impl Builder {
+        /// Well-known arc, that `name` denotes at the next position
+        fn well_known(&self, name: &str) -> Option<Arc> {
+            match self {
+                Builder::Empty => well_known(None, name),
+                Builder::Arc0(arc0) => well_known(Some(*arc0), name),
+                Builder::Oid(_) => None,
+            }
+        }
+
+        fn push(&mut self, arc: Arc, span: Range<usize>) -> Result<(), Asn1ParseError> {
+            let invalid_root = || Asn1ParseError::new(Asn1ErrorKind::InvalidRoot, span.clone());
+            match self {
+                Builder::Empty => {
+                    let arc0 = u8::try_from(arc).ok().and_then(|arc| Arc0::new(arc).ok());
+                    *self = Builder::Arc0(arc0.ok_or_else(invalid_root)?);
+                }
+                Builder::Arc0(arc0) => {
+                    let arc1 = Arc1::new(arc).map_err(|_| invalid_root())?;
+                    let root = RootOid::new(*arc0, arc1).map_err(|_| invalid_root())?;
+                    *self = Builder::Oid(AbsoluteOidVec::from_root(root));
+                }
+                Builder::Oid(oid) => oid.push(arc),
+            }
+            Ok(())
+        }
+    }
+}
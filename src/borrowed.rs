@@ -1,69 +1,118 @@
-use core::mem::size_of;
-
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
+use core::fmt;
 
-use crate::{Arc, Arc0, Arc1, Position};
+use crate::{
+    encode::encode_b128,
+    iter::{AbsoluteErrors, RelativeErrors},
+    Arc, Arc0, Arc1, Position, ARC_LEN,
+};
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
-#[repr(transparent)]
 /// Represents first two arcs of an OID.
 ///
 /// # Invariants
 /// First arc MUST be one of `0, 1, 2`(see [Arc0]),
-/// second arc MUST be in range `0 ..= 39`(see [Arc1]).
-/// Arcs are composed into a byte as `arc0 * 40 + arc1`,
-/// Thus the stored byte must be within range `0..=119`(`0..=0x77`), see [MAX_ROOT_BYTE].
-pub struct RootOid(u8);
+/// second arc MUST be in range `0 ..= 39`, unless the first arc is `2`(see [Arc1]).
+/// Arcs are composed into a single subidentifier as `arc0 * 40 + arc1`,
+/// which is base-128 encoded the same way as the rest of the arcs.
+///
+/// Thus, any [Arc] value is a valid root subidentifier:
+/// values below `80` belong to arcs `0` and `1`, and the rest are `2.(value - 80)`.
+///
+/// The root used to be a single byte, note the breaking changes:
+/// - `RootOid::from_u8_unchecked` and `RootOid::into_u8` are gone,
+///   use [RootOid::from_subidentifier] and [RootOid::subidentifier] instead
+/// - [MAX_ROOT_BYTE] is `0x7f` rather than `119`, so [RootOid::from_u8]
+///   accepts `0x78..=0x7f` as `2.40..=2.47`
+/// - [RootOid::new] returns a `Result`, [Arc1] holds an [Arc] instead of `u8`
+pub struct RootOid {
+    /// Base-128 encoded subidentifier, aligned to the end of the buffer.
+    /// Bytes before `start` are zero.
+    bytes: [u8; ARC_LEN],
+    start: u8,
+}
 
-pub const MAX_ROOT_BYTE: u8 = 40 * 2 + 39;
+/// Largest root subidentifier that fits into a single byte
+///
+/// Used to be `119`(`0x77`), back when the root was limited to a single byte
+pub const MAX_ROOT_BYTE: u8 = 0x7f;
 
 impl RootOid {
-    /// # Safety
-    /// `byte` must be within range `0..=119`(`0..=0x77`), see [RootOid]
-    pub const unsafe fn from_u8_unchecked(byte: u8) -> RootOid {
-        RootOid(byte)
+    /// Construct root from the combined subidentifier `arc0 * 40 + arc1`
+    pub const fn from_subidentifier(value: Arc) -> RootOid {
+        let (bytes, start) = encode_b128(value);
+        RootOid {
+            bytes,
+            start: start as u8,
+        }
     }
 
-    pub const fn into_u8(self) -> u8 {
-        self.0
+    /// Combined value of the first two arcs, `arc0 * 40 + arc1`
+    pub fn subidentifier(&self) -> Arc {
+        // SAFETY: `RootOid` always stores a single valid arc
+        let oid = unsafe { RelativeOid::from_bytes_unchecked(self.as_bytes()) };
+        oid.arcs().next().unwrap()
     }
 
-    const fn check(byte: u8) -> Result<(), RootError> {
+    /// Construct root from a single byte encoding
+    ///
+    /// Roots above [MAX_ROOT_BYTE] take several bytes, see [RootOid::from_subidentifier]
+    pub const fn from_u8(byte: u8) -> Result<RootOid, RootError> {
         if byte > MAX_ROOT_BYTE {
-            return Err(RootError(()));
+            return Err(RootError(None));
         }
-        Ok(())
+        Ok(RootOid::from_subidentifier(byte as Arc))
     }
 
-    pub const fn from_u8(byte: u8) -> Result<RootOid, RootError> {
-        if let Err(e) = RootOid::check(byte) {
-            return Err(e);
+    pub const fn new(arc0: Arc0, arc1: Arc1) -> Result<RootOid, RootError> {
+        let arc1 = arc1.as_arc();
+        if arc1 >= 40 && !matches!(arc0, Arc0::JointIsoItuT) {
+            return Err(RootError(None));
         }
-        Ok(unsafe { RootOid::from_u8_unchecked(byte) })
+        // `arc1` guarantees there is no overflow, see [MAX_ARC1](crate::MAX_ARC1)
+        Ok(RootOid::from_subidentifier(arc0 as Arc * 40 + arc1))
     }
 
-    pub const fn new(arc0: Arc0, arc1: Arc1) -> RootOid {
-        let byte = arc0 as u8 * 40 + arc1.as_u8();
-        // SAFETY: byte was just constructed,
-        // `arc0` guarantees it is one of `0, 1, 2`,
-        // `arc1` guarantees it is withing range `0..39`
-        unsafe { RootOid::from_u8_unchecked(byte) }
+    /// Base-128 encoded subidentifier
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[self.start as usize..]
     }
 
     pub fn as_absolute(&self) -> &AbsoluteOid {
-        let bytes = core::slice::from_ref(&self.0);
         unsafe {
-            // SAFETY: bytes are one element array
-            // and the first byte invariants are exactly
-            // the invariants of RootOid
-            AbsoluteOid::from_bytes_unchecked(bytes)
+            // SAFETY: bytes contain exactly one subidentifier,
+            // and any subidentifier is a valid root
+            AbsoluteOid::from_bytes_unchecked(self.as_bytes())
         }
     }
 }
 
+/// Invalid first two arcs
+///
+/// When decoding bytes, carries the error in the base-128 encoding of the root subidentifier.
 #[derive(Debug, Clone)]
-pub struct RootError(());
+pub struct RootError(pub(crate) Option<B128Error>);
+
+impl RootError {
+    /// Error in the encoding of the root subidentifier,
+    /// `None` if the root was built from arcs out of range
+    pub fn b128_error(&self) -> Option<&B128Error> {
+        self.0.as_ref()
+    }
+}
+
+impl fmt::Display for RootError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid root of an absolute oid")
+    }
+}
+
+impl crate::error::Error for RootError {
+    fn source(&self) -> Option<&(dyn crate::error::Error + 'static)> {
+        self.0.as_ref().map(|error| error as _)
+    }
+}
 
 #[derive(PartialEq, Eq, Hash)]
 #[repr(transparent)]
@@ -136,14 +185,30 @@ impl RelativeOid {
         &mut self.0
     }
 
-    pub(crate) fn check_bytes(bytes: &[u8]) -> Result<(), B128Error> {
+    pub(crate) const fn check_bytes(bytes: &[u8]) -> Result<(), B128Error> {
         if let Some(error) = next_b128_error(bytes) {
             return Err(error);
         }
         Ok(())
     }
 
-    // FIXME: add iterator over all errors (might be usefull for error correction, or reporting)
+    /// All errors in `bytes`, unlike [from_bytes](RelativeOid::from_bytes), which stops on the first one
+    ///
+    /// Each error comes with the byte range of the offending subidentifier.
+    /// ```
+    /// # use oid_str::{B128ErrorKind, RelativeOid};
+    /// let mut errors = RelativeOid::errors(b"\x01\x80\x01\x02\x86");
+    /// let (error, range) = errors.next().unwrap();
+    /// assert!(matches!(error.kind, B128ErrorKind::ZeroByteWithCont));
+    /// assert_eq!(range, 1..3);
+    /// let (error, range) = errors.next().unwrap();
+    /// assert!(matches!(error.kind, B128ErrorKind::Unfinished));
+    /// assert_eq!(range, 4..5);
+    /// assert!(errors.next().is_none());
+    /// ```
+    pub fn errors(bytes: &[u8]) -> RelativeErrors<'_> {
+        RelativeErrors::new(bytes, 0)
+    }
 
     pub fn from_bytes(bytes: &[u8]) -> Result<&RelativeOid, B128Error> {
         RelativeOid::check_bytes(bytes)?;
@@ -188,10 +253,10 @@ impl From<&RelativeOid> for Box<RelativeOid> {
 /// Each number is refered to as a [Arc].
 ///
 /// # Invariants
-/// Must contain at least 1 byte.
-/// First byte value must satisfy [RootOid] invariants
+/// Must contain at least 1 arc.
+/// The first arc is the [RootOid] subidentifier, it spans one or more bytes.
 ///
-/// The rest of the bytes(`&bytes[1..]`) must conform to [RelativeOid] invariants
+/// All bytes(including the root) must conform to [RelativeOid] invariants
 pub struct AbsoluteOid([u8]);
 
 impl AbsoluteOid {
@@ -242,16 +307,45 @@ impl AbsoluteOid {
         &mut self.0
     }
 
-    pub(crate) fn check_bytes(bytes: &[u8]) -> Result<(), OidDecodingError> {
+    pub(crate) const fn check_bytes(bytes: &[u8]) -> Result<(), OidDecodingError> {
         if bytes.is_empty() {
             return Err(OidDecodingError::Empty);
         }
-        let first_byte = bytes[0];
-        RootOid::check(first_byte)?;
-        RelativeOid::check_bytes(&bytes[1..])?;
+        // if there is no terminating byte, the root is unfinished
+        let root_len = match root_len(bytes) {
+            Some(len) => len,
+            None => bytes.len(),
+        };
+        let (root, tail) = bytes.split_at(root_len);
+        if let Some(error) = next_b128_error(root) {
+            return Err(OidDecodingError::Root(RootError(Some(error))));
+        }
+        if let Some(mut error) = next_b128_error(tail) {
+            error.pos = error.pos.saturating_add(to_position(root_len));
+            return Err(OidDecodingError::Base128(error));
+        }
         Ok(())
     }
 
+    /// All errors in `bytes`, unlike [from_bytes](AbsoluteOid::from_bytes), which stops on the first one
+    ///
+    /// Each error comes with the byte range of the offending subidentifier,
+    /// positions are counted from the start of `bytes`.
+    /// Errors in the root are [OidDecodingError::Root], same as in [from_bytes](AbsoluteOid::from_bytes).
+    /// ```
+    /// # use oid_str::{AbsoluteOid, B128ErrorKind, OidDecodingError};
+    /// let errors: Vec<_> = AbsoluteOid::errors(b"\x80\x2b\x06\x80\x01").collect();
+    /// let OidDecodingError::Root(root) = &errors[0].0 else { panic!() };
+    /// let e = root.b128_error().unwrap();
+    /// assert!(matches!(e.kind, B128ErrorKind::ZeroByteWithCont) && e.pos == 0);
+    /// assert_eq!(errors[0].1, 0..2);
+    /// assert!(matches!(errors[1], (OidDecodingError::Base128(_), _)));
+    /// assert_eq!(errors[1].1, 3..5);
+    /// ```
+    pub fn errors(bytes: &[u8]) -> AbsoluteErrors<'_> {
+        AbsoluteErrors::new(bytes)
+    }
+
     pub fn from_bytes(bytes: &[u8]) -> Result<&AbsoluteOid, OidDecodingError> {
         AbsoluteOid::check_bytes(bytes)?;
         // SAFETY: check above confirms that bytes are parsable into `Vec<Arc>`
@@ -279,41 +373,81 @@ impl From<&AbsoluteOid> for Box<AbsoluteOid> {
 impl AbsoluteOid {
     /// First two arcs
     pub fn root(&self) -> RootOid {
-        if cfg!(debug_assertions) {
-            RootOid::from_u8(self.as_bytes()[0]).unwrap();
-        }
-        // SAFETY: first byte is always present and contains a valid value for [RootOid]
-        unsafe { RootOid::from_u8_unchecked(*self.as_bytes().get_unchecked(0)) }
+        // SAFETY: every byte of `AbsoluteOid` conforms to `RelativeOid` invariants
+        let oid = unsafe { RelativeOid::from_bytes_unchecked(self.as_bytes()) };
+        // `AbsoluteOid` always contains at least the root arc
+        RootOid::from_subidentifier(oid.arcs().next().unwrap())
+    }
+
+    /// Length of the [RootOid] subidentifier in bytes
+    pub(crate) fn root_len(&self) -> usize {
+        // `AbsoluteOid` always contains a finished root arc
+        root_len(self.as_bytes()).unwrap()
     }
 
     /// All arcs except the first two(the [RootOid])
     pub fn tail(&self) -> &RelativeOid {
-        debug_assert_eq!(size_of::<RootOid>(), 1);
+        let root_len = self.root_len();
         if cfg!(debug_assertions) {
-            RelativeOid::check_bytes(&self.as_bytes()[1..]).unwrap();
+            RelativeOid::check_bytes(&self.as_bytes()[root_len..]).unwrap();
         }
-        // SAFETY: `RootOid` always takes exactly 1 byte, so the rest is a valid `RelativeOid`
-        unsafe { RelativeOid::from_bytes_unchecked(self.as_bytes().get_unchecked(1..)) }
+        // SAFETY: the root is a single finished arc, so the rest is a valid `RelativeOid`
+        unsafe { RelativeOid::from_bytes_unchecked(self.as_bytes().get_unchecked(root_len..)) }
     }
 
     /// All arcs except the first two(the [RootOid])
     pub fn tail_mut(&mut self) -> &mut RelativeOid {
-        debug_assert_eq!(size_of::<RootOid>(), 1);
+        let root_len = self.root_len();
         if cfg!(debug_assertions) {
-            RelativeOid::check_bytes(&self.as_bytes()[1..]).unwrap();
+            RelativeOid::check_bytes(&self.as_bytes()[root_len..]).unwrap();
         }
-        // SAFETY: `RootOid` always takes exactly 1 byte, so the rest is a valid `RelativeOid`
-        unsafe { RelativeOid::from_mut_bytes_unchecked(self.as_mut_bytes().get_unchecked_mut(1..)) }
+        // SAFETY: the root is a single finished arc, so the rest is a valid `RelativeOid`
+        unsafe { RelativeOid::from_mut_bytes_unchecked(self.as_mut_bytes().get_unchecked_mut(root_len..)) }
     }
 }
 
+/// Length of the first arc, `None` if the arc is unfinished
+pub(crate) const fn root_len(bytes: &[u8]) -> Option<usize> {
+    let mut pos = 0;
+    while pos < bytes.len() {
+        if bytes[pos] & 0x80 == 0 {
+            return Some(pos + 1);
+        }
+        pos += 1;
+    }
+    None
+}
+
 #[derive(Debug, Clone)]
 pub enum OidDecodingError {
     Empty,
+    /// The first subidentifier is not a valid base-128 number
     Root(RootError),
+    /// Error in the arcs after the root,
+    /// position is counted from the start of the [AbsoluteOid]
     Base128(B128Error),
 }
 
+impl fmt::Display for OidDecodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OidDecodingError::Empty => f.write_str("absolute oid must not be empty"),
+            OidDecodingError::Root(_) => f.write_str("invalid first subidentifier"),
+            OidDecodingError::Base128(error) => write!(f, "invalid arc at byte {}", error.pos),
+        }
+    }
+}
+
+impl crate::error::Error for OidDecodingError {
+    fn source(&self) -> Option<&(dyn crate::error::Error + 'static)> {
+        match self {
+            OidDecodingError::Empty => None,
+            OidDecodingError::Root(error) => Some(error),
+            OidDecodingError::Base128(error) => Some(error),
+        }
+    }
+}
+
 impl From<RootError> for OidDecodingError {
     fn from(error: RootError) -> Self {
         OidDecodingError::Root(error)
@@ -339,72 +473,119 @@ pub enum B128ErrorKind {
     Unfinished,
 }
 
-fn next_b128_error(bytes: &[u8]) -> Option<B128Error> {
-    const N_BYTES: u8 = core::mem::size_of::<Arc>() as u8;
-    const FIRST_BYTE_N_BITS: u8 = (N_BYTES * 8) % 7;
+impl fmt::Display for B128Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.pos)
+    }
+}
+
+impl crate::error::Error for B128Error {}
+
+impl fmt::Display for B128ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            B128ErrorKind::OutOfRange => "arc does not fit into `Arc`",
+            B128ErrorKind::ZeroByteWithCont => "arc starts with a redundant 0x80 byte",
+            B128ErrorKind::Unfinished => "arc is missing its terminating byte",
+        })
+    }
+}
+
+/// Byte offset as [Position], offsets past `Position::MAX` are clamped
+pub(crate) const fn to_position(pos: usize) -> Position {
+    if pos > Position::MAX as usize {
+        return Position::MAX;
+    }
+    pos as Position
+}
+
+pub(crate) const fn next_b128_error(bytes: &[u8]) -> Option<B128Error> {
+    b128_error(bytes, true)
+}
+
+/// Same as [next_b128_error], but allows arcs of any size
+pub(crate) const fn next_unbounded_b128_error(bytes: &[u8]) -> Option<B128Error> {
+    b128_error(bytes, false)
+}
+
+const fn b128_error(bytes: &[u8], bounded: bool) -> Option<B128Error> {
+    // FIXME: this might be a good candidate for simd optimisation
+    // indices instead of iterators, so that the check can run in const context
+    let mut i = 0;
+    while i < bytes.len() {
+        let (kind, end) = subidentifier_error(bytes, i, bounded);
+        if let Some(kind) = kind {
+            return Some(B128Error {
+                kind,
+                pos: to_position(i),
+            });
+        }
+        i = end;
+    }
+
+    None
+}
+
+/// Checks a single subidentifier starting at `start`
+///
+/// Returns the first error in it, if any, and the position right after the subidentifier
+pub(crate) const fn subidentifier_error(
+    bytes: &[u8],
+    start: usize,
+    bounded: bool,
+) -> (Option<B128ErrorKind>, usize) {
+    const N_BITS: u32 = Arc::BITS;
+    // number of bytes, that carry exactly 7 bits of `Arc`
+    const N_BYTES: usize = (N_BITS / 7) as usize;
+    const FIRST_BYTE_N_BITS: u8 = (N_BITS % 7) as u8;
     const FIRST_BYTE_MASK: u8 = 0x80 - (1 << FIRST_BYTE_N_BITS);
 
     // each byte stores 7 bits
-    // whatever the `Arc` size is, it is not divisible by 7 (niether 8, 16, 32, 64, etc)
+    // whatever the `Arc` size is, it is not divisible by 7 (niether 8, 16, 32, 64, 128)
     // in order to be able to decode any Arc value,
     // we need to discard leading 0 bits in the first byte
 
-    // FIXME: this might be a good candidate for simd optimisation
-    let mut iter = bytes.iter().enumerate();
-    while let Some((pos, &byte)) = iter.next() {
-        let pos = pos as Position;
-
-        match byte {
-            0..=0x7f => continue,
-            0x80 => {
-                return Some(B128Error {
-                    kind: B128ErrorKind::ZeroByteWithCont,
-                    pos,
-                })
+    let byte = bytes[start];
+    let mut kind = match byte {
+        0..=0x7f => return (None, start + 1),
+        0x80 => Some(B128ErrorKind::ZeroByteWithCont),
+        _ => None,
+    };
+
+    // we slightly over/underestimate number of bits, but this is ok
+    // if the number is valid, we underestimate the number of bits
+    // if the number is invalid, we overestimate the number of bits
+    //
+    // suppose `Arc = u16`,
+    // then N_BYTES = 2, mask = 0x80 - 0x04 = 0x7c = 0b0111_1100,
+    // and there are the following posibilites:
+    //      [7f      ] => first_byte = 1    n_bytes = 1    value = 0x007f
+    //      [ff 7f   ] => first_byte = 1    n_bytes = 2    value = 0x3fff
+    //      [ff ff 03] => first_byte = 0    n_bytes = 2    value = 0xffff
+    //      [ff ff 04] => first_byte = 1    n_bytes = 3    invalid
+    let first_byte = if byte & FIRST_BYTE_MASK == 0 { 0 } else { 1 };
+
+    let mut n_bytes = first_byte;
+    let mut i = start + 1;
+
+    // keep going after an error to find where the subidentifier ends
+    loop {
+        if i == bytes.len() {
+            // We've exhausted `bytes`, but haven't hit terminating byte,
+            // so we have an unfinished number
+            if kind.is_none() {
+                kind = Some(B128ErrorKind::Unfinished);
             }
-            _ => {}
+            return (kind, i);
         }
-
-        // we slightly over/underestimate number of bits, but this is ok
-        // if the number is valid, we underestimate the number of bits
-        // if the number is invalid, we overestimate the number of bits
-        //
-        // suppose `Arc = u16`,
-        // then mask = 0x80 - 0x04 = 0x7c = 0b0111_1100,
-        // and there are the following posibilites:
-        //      [7f      ] => first_byte = 1    n_bytes = 1    value = 0x007f
-        //      [ff 7f   ] => first_byte = 1    n_bytes = 2    value = 0x3fff
-        //      [ff ff 03] => first_byte = 0    n_bytes = 2    value = 0xffff
-        //      [ff ff 04] => first_byte = 1    n_bytes = 3    invalid
-        let first_byte = if byte & FIRST_BYTE_MASK == 0 { 0 } else { 1 };
-
-        let mut n_bytes = first_byte;
-
-        loop {
-            match iter.next() {
-                Some((_, &byte)) => {
-                    n_bytes += 1;
-                    if n_bytes > N_BYTES {
-                        return Some(B128Error {
-                            kind: B128ErrorKind::OutOfRange,
-                            pos,
-                        });
-                    }
-                    if byte < 0x80 {
-                        break;
-                    }
-                }
-                None => {
-                    // We've exhausted `iter`, but haven't hit terminating byte,
-                    // so we have an unfinished number
-                    return Some(B128Error {
-                        kind: B128ErrorKind::Unfinished,
-                        pos,
-                    });
-                }
-            }
+        let byte = bytes[i];
+        i += 1;
+        n_bytes += 1;
+        if bounded && n_bytes > N_BYTES && kind.is_none() {
+            kind = Some(B128ErrorKind::OutOfRange);
+        }
+        if byte < 0x80 {
+            return (kind, i);
         }
     }
-
-    None
 }
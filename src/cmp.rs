@@ -0,0 +1,192 @@
+//! Ordering of oids by arc values
+//!
+//! Oids are ordered lexicographically by their arcs, i.e. `1.3.6.1.2 < 1.3.6.1.10 < 1.3.6.2`.
+//!
+//! # Comparing encoded bytes
+//!
+//! Arcs are never compared after decoding, encoded bytes carry enough information.
+//!
+//! Encoding is minimal (no leading `0x80` bytes), so among two encoded arcs
+//! the longer one is the larger one.
+//! Encoded arcs of the same length are ordered the same way as their bytes,
+//! base-128 digits go from the most significant to the least significant one.
+//!
+//! Consider the first byte `i` where two oids differ.
+//! All bytes before `i` are equal, so both oids are split into arcs the same way up to `i`,
+//! and byte `i` belongs to the same (in order) arc of both oids, with equal preceding digits.
+//! - if either byte at `i` is a terminating one (`< 0x80`), its arc ends at `i`,
+//!   the other arc is either of the same length, or longer and has a continuation byte (`>= 0x80`) at `i`.
+//!   In both cases comparing bytes at `i` gives the order of arcs.
+//! - otherwise, the longer of the two arcs is the larger one.
+//!   Only if both are of the same length, we compare bytes at `i`.
+//!
+//! If there is no such byte, one oid is a prefix of the other, and the shorter one is less.
+//! Since oids always end with a finished arc, the prefix is a whole number of arcs.
+//!
+//! Note that plain bytewise comparison is wrong only in the last case,
+//! e.g. `[0x81, 0x80, 0x00] (16384)` is less than `[0x82, 0x00] (256)` bytewise.
+//!
+//! # Absolute oids
+//!
+//! The first subidentifier of [AbsoluteOid] is `arc0 * 40 + arc1`.
+//! It is monotone in `(arc0, arc1)`, because `arc1 < 40` unless `arc0 = 2`,
+//! so absolute oids are compared by their subidentifiers the same way as relative ones.
+
+use core::cmp::Ordering;
+
+use crate::{
+    AbsoluteOid, AbsoluteOidArray, RelativeOid, RelativeOidArray, RootOid, StaticAbsoluteOid, StaticRelativeOid, UnboundedAbsoluteOid,
+    UnboundedRelativeOid,
+};
+
+/// Compares two sequences of base-128 encoded arcs by arc values
+///
+/// Both sequences must consist of finished minimally encoded arcs.
+pub(crate) fn cmp_encoded(a: &[u8], b: &[u8]) -> Ordering {
+    let i = match a.iter().zip(b).position(|(a, b)| a != b) {
+        Some(i) => i,
+        None => return a.len().cmp(&b.len()),
+    };
+    if a[i] < 0x80 || b[i] < 0x80 {
+        return a[i].cmp(&b[i]);
+    }
+    arc_len(&a[i..])
+        .cmp(&arc_len(&b[i..]))
+        .then(a[i].cmp(&b[i]))
+}
+
+/// Number of bytes up to and including the terminating one
+fn arc_len(bytes: &[u8]) -> usize {
+    bytes.iter().position(|&byte| byte < 0x80).map_or(bytes.len(), |pos| pos + 1)
+}
+
+macro_rules! impl_ord {
+    ($($ty:ty),*) => {$(
+        impl Ord for $ty {
+            fn cmp(&self, other: &Self) -> Ordering {
+                cmp_encoded(self.as_bytes(), other.as_bytes())
+            }
+        }
+
+        impl PartialOrd for $ty {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+    )*};
+}
+
+impl_ord!(RelativeOid, AbsoluteOid, UnboundedRelativeOid, UnboundedAbsoluteOid);
+
+impl Ord for RootOid {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.subidentifier().cmp(&other.subidentifier())
+    }
+}
+
+impl PartialOrd for RootOid {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// `Static*` types compare the same way as the types they dereference to
+macro_rules! impl_static_cmp {
+    ($($ty:ident),*) => {$(
+        impl PartialEq for $ty<'_> {
+            fn eq(&self, other: &Self) -> bool {
+                **self == **other
+            }
+        }
+
+        impl Eq for $ty<'_> {}
+
+        impl core::hash::Hash for $ty<'_> {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                (**self).hash(state)
+            }
+        }
+
+        impl Ord for $ty<'_> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                (**self).cmp(&**other)
+            }
+        }
+
+        impl PartialOrd for $ty<'_> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+    )*};
+}
+
+impl_static_cmp!(StaticAbsoluteOid, StaticRelativeOid);
+
+// inline arrays compare only the used bytes
+macro_rules! impl_array_cmp {
+    ($($ty:ident),*) => {$(
+        impl<const N: usize> PartialEq for $ty<N> {
+            fn eq(&self, other: &Self) -> bool {
+                **self == **other
+            }
+        }
+
+        impl<const N: usize> Eq for $ty<N> {}
+
+        impl<const N: usize> core::hash::Hash for $ty<N> {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                (**self).hash(state)
+            }
+        }
+
+        impl<const N: usize> Ord for $ty<N> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                (**self).cmp(&**other)
+            }
+        }
+
+        impl<const N: usize> PartialOrd for $ty<N> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+    )*};
+}
+
+impl_array_cmp!(AbsoluteOidArray, RelativeOidArray);
+
+#[cfg(feature = "alloc")]
+mod owned {
+    use super::*;
+    use crate::{
+        AbsoluteOidVec, RelativeOidVec, SmallAbsoluteOid, SmallRelativeOid, UnboundedAbsoluteOidVec,
+        UnboundedRelativeOidVec,
+    };
+
+    // owned types are ordered as their borrowed counterparts, so `Borrow` is consistent
+    macro_rules! impl_owned_ord {
+        ($($ty:ty),*) => {$(
+            impl Ord for $ty {
+                fn cmp(&self, other: &Self) -> Ordering {
+                    (**self).cmp(&**other)
+                }
+            }
+
+            impl PartialOrd for $ty {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+        )*};
+    }
+
+    impl_owned_ord!(
+        RelativeOidVec,
+        AbsoluteOidVec,
+        UnboundedRelativeOidVec,
+        UnboundedAbsoluteOidVec,
+        SmallRelativeOid,
+        SmallAbsoluteOid
+    );
+}
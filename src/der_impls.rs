@@ -0,0 +1,186 @@
+//! Integration with RustCrypto `der` and `const-oid` crates, enabled with `der` feature
+//!
+//! `der` has no universal `RELATIVE-OID` tag, so relative oids only implement
+//! value traits and have to be used with implicit tagging.
+
+use core::{cmp::Ordering, convert::TryFrom};
+
+use const_oid::ObjectIdentifier;
+use der::{DecodeValue, EncodeValue, FixedTag, Header, Length, Reader, Tag, ValueOrd, Writer};
+
+use crate::{AbsoluteOid, OidDecodingError, RelativeOid};
+
+impl<'a> DecodeValue<'a> for &'a AbsoluteOid {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        let bytes = reader.read_slice(header.length)?;
+        AbsoluteOid::from_bytes(bytes).map_err(|_| header.tag.value_error())
+    }
+}
+
+impl EncodeValue for &AbsoluteOid {
+    fn value_len(&self) -> der::Result<Length> {
+        Length::try_from(self.as_bytes().len())
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        writer.write(self.as_bytes())
+    }
+}
+
+impl FixedTag for &AbsoluteOid {
+    const TAG: Tag = Tag::ObjectIdentifier;
+}
+
+// DER requires `SET OF` to be sorted by encoding, which is not the same as [Ord]
+impl ValueOrd for &AbsoluteOid {
+    fn value_cmp(&self, other: &Self) -> der::Result<Ordering> {
+        Ok(self.as_bytes().cmp(other.as_bytes()))
+    }
+}
+
+impl<'a> DecodeValue<'a> for &'a RelativeOid {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        let bytes = reader.read_slice(header.length)?;
+        RelativeOid::from_bytes(bytes).map_err(|_| header.tag.value_error())
+    }
+}
+
+impl EncodeValue for &RelativeOid {
+    fn value_len(&self) -> der::Result<Length> {
+        Length::try_from(self.as_bytes().len())
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        writer.write(self.as_bytes())
+    }
+}
+
+impl ValueOrd for &RelativeOid {
+    fn value_cmp(&self, other: &Self) -> der::Result<Ordering> {
+        Ok(self.as_bytes().cmp(other.as_bytes()))
+    }
+}
+
+// `ObjectIdentifier::from_bytes` accepts padded arcs, so the conversion is fallible
+impl<'a> TryFrom<&'a ObjectIdentifier> for &'a AbsoluteOid {
+    type Error = OidDecodingError;
+
+    fn try_from(oid: &'a ObjectIdentifier) -> Result<Self, Self::Error> {
+        AbsoluteOid::from_bytes(oid.as_bytes())
+    }
+}
+
+impl TryFrom<&AbsoluteOid> for ObjectIdentifier {
+    type Error = const_oid::Error;
+
+    fn try_from(oid: &AbsoluteOid) -> Result<Self, Self::Error> {
+        ObjectIdentifier::from_bytes(oid.as_bytes())
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod owned {
+    use core::{cmp::Ordering, convert::TryFrom};
+
+    use const_oid::ObjectIdentifier;
+    use der::{DecodeValue, EncodeValue, FixedTag, Header, Length, Reader, Tag, ValueOrd, Writer};
+
+    use crate::{
+        AbsoluteOid, AbsoluteOidVec, OidDecodingError, RelativeOid, RelativeOidVec, SmallAbsoluteOid,
+        SmallRelativeOid,
+    };
+
+    // owned oids decode through the borrowed ones and encode their contents
+    macro_rules! impl_owned_absolute {
+        ($($ty:ident),*) => {$(
+            impl<'a> DecodeValue<'a> for $ty {
+                fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+                    let oid = <&AbsoluteOid>::decode_value(reader, header)?;
+                    Ok($ty::from_oid(oid))
+                }
+            }
+
+            impl EncodeValue for $ty {
+                fn value_len(&self) -> der::Result<Length> {
+                    self.as_oid().value_len()
+                }
+
+                fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+                    self.as_oid().encode_value(writer)
+                }
+            }
+
+            impl FixedTag for $ty {
+                const TAG: Tag = Tag::ObjectIdentifier;
+            }
+
+            impl ValueOrd for $ty {
+                fn value_cmp(&self, other: &Self) -> der::Result<Ordering> {
+                    self.as_oid().value_cmp(&other.as_oid())
+                }
+            }
+
+            impl TryFrom<ObjectIdentifier> for $ty {
+                type Error = OidDecodingError;
+
+                fn try_from(oid: ObjectIdentifier) -> Result<Self, Self::Error> {
+                    $ty::try_from(&oid)
+                }
+            }
+
+            impl TryFrom<&ObjectIdentifier> for $ty {
+                type Error = OidDecodingError;
+
+                fn try_from(oid: &ObjectIdentifier) -> Result<Self, Self::Error> {
+                    Ok($ty::from_oid(<&AbsoluteOid>::try_from(oid)?))
+                }
+            }
+
+            impl TryFrom<&$ty> for ObjectIdentifier {
+                type Error = const_oid::Error;
+
+                fn try_from(oid: &$ty) -> Result<Self, Self::Error> {
+                    ObjectIdentifier::try_from(oid.as_oid())
+                }
+            }
+
+            impl TryFrom<$ty> for ObjectIdentifier {
+                type Error = const_oid::Error;
+
+                fn try_from(oid: $ty) -> Result<Self, Self::Error> {
+                    ObjectIdentifier::try_from(oid.as_oid())
+                }
+            }
+        )*};
+    }
+
+    macro_rules! impl_owned_relative {
+        ($($ty:ident),*) => {$(
+            impl<'a> DecodeValue<'a> for $ty {
+                fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+                    let oid = <&RelativeOid>::decode_value(reader, header)?;
+                    Ok($ty::from_oid(oid))
+                }
+            }
+
+            impl EncodeValue for $ty {
+                fn value_len(&self) -> der::Result<Length> {
+                    self.as_oid().value_len()
+                }
+
+                fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+                    self.as_oid().encode_value(writer)
+                }
+            }
+
+            impl ValueOrd for $ty {
+                fn value_cmp(&self, other: &Self) -> der::Result<Ordering> {
+                    self.as_oid().value_cmp(&other.as_oid())
+                }
+            }
+        )*};
+    }
+
+    impl_owned_absolute!(AbsoluteOidVec, SmallAbsoluteOid);
+    impl_owned_relative!(RelativeOidVec, SmallRelativeOid);
+}
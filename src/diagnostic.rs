@@ -0,0 +1,179 @@
+//! Detailed errors of dotted text parsing, see [ParseOptions::diagnose_absolute]
+//!
+//! Diagnostics are built from the [OidParsingError] of the regular parsing,
+//! so they can't disagree with it.
+
+use core::{fmt, ops::Range};
+
+use crate::{str::StrArcs, OidParsingError, ParseOptions};
+
+/// Detailed parsing error, with the byte span and the index of the failed arc
+#[derive(Debug, Clone)]
+pub struct ParseDiagnostic {
+    pub kind: ParseErrorKind,
+    /// Bytes of the input, that caused the error, the whole arc for arc errors
+    pub span: Range<usize>,
+    /// Index of the failed arc, the first arc is 0
+    pub arc: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// Not a digit or a dot, span points at the character
+    InvalidChar,
+    /// Nothing between two dots, or no arcs at all
+    EmptyArc,
+    /// Text ends with a dot
+    TrailingDot,
+    /// Arc has leading zeros, in strict mode
+    LeadingZero,
+    /// Arc does not fit into [Arc](crate::Arc)
+    ArcOverflow,
+    /// First arc is not in `0..=2`
+    InvalidArc0,
+    /// Second arc is above 39 under arcs 0 and 1, or above [MAX_ARC1](crate::MAX_ARC1) under arc 2
+    InvalidArc1,
+    /// Absolute oid has only one arc
+    MissingArc1,
+    /// Leading dot is forbidden by [ParseOptions]
+    UnexpectedLeadingDot,
+    /// Leading dot is required by [ParseOptions]
+    MissingLeadingDot,
+}
+
+impl fmt::Display for ParseDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}..{} (arc {})", self.kind, self.span.start, self.span.end, self.arc)
+    }
+}
+
+impl crate::error::Error for ParseDiagnostic {}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ParseErrorKind::InvalidChar => "invalid character",
+            ParseErrorKind::EmptyArc => "expected an arc",
+            ParseErrorKind::TrailingDot => "trailing dot",
+            ParseErrorKind::LeadingZero => "arc has leading zeros",
+            ParseErrorKind::ArcOverflow => "arc is out of range",
+            ParseErrorKind::InvalidArc0 => "first arc must be 0, 1 or 2",
+            ParseErrorKind::InvalidArc1 => "second arc is out of range",
+            ParseErrorKind::MissingArc1 => "absolute oid must have at least two arcs",
+            ParseErrorKind::UnexpectedLeadingDot => "leading dot is not allowed",
+            ParseErrorKind::MissingLeadingDot => "expected a leading dot",
+        })
+    }
+}
+
+impl ParseDiagnostic {
+    fn new(kind: ParseErrorKind, span: Range<usize>, arc: usize) -> ParseDiagnostic {
+        ParseDiagnostic { kind, span, arc }
+    }
+
+    /// Formats `input` with a caret line under the span, `input` must be the parsed text
+    /// ```
+    /// # use oid_str::ParseOptions;
+    /// let error = ParseOptions::new().diagnose_absolute("1.3.6.x").unwrap_err();
+    /// assert_eq!(error.render("1.3.6.x").to_string(), "1.3.6.x\n      ^ invalid character");
+    /// ```
+    pub fn render<'a>(&'a self, input: &'a str) -> Render<'a> {
+        Render { diagnostic: self, input }
+    }
+}
+
+/// [ParseDiagnostic] with the input, see [ParseDiagnostic::render]
+pub struct Render<'a> {
+    diagnostic: &'a ParseDiagnostic,
+    input: &'a str,
+}
+
+impl fmt::Display for Render<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let span = &self.diagnostic.span;
+        let column = self.input.get(..span.start).map_or(0, |before| before.chars().count());
+        let width = self.input.get(span.clone()).map_or(0, |s| s.chars().count()).max(1);
+        writeln!(f, "{}", self.input)?;
+        write!(f, "{:column$}", "", column = column)?;
+        for _ in 0..width {
+            f.write_str("^")?;
+        }
+        write!(f, " {}", self.diagnostic.kind)
+    }
+}
+
+impl ParseOptions {
+    /// Explains why [ParseOptions::parse_absolute] fails on `s`
+    ///
+    /// Returns `Ok` exactly when parsing succeeds, spans point into `s`.
+    /// ```
+    /// # use oid_str::{ParseErrorKind, ParseOptions};
+    /// let error = ParseOptions::new().diagnose_absolute("1.50.6").unwrap_err();
+    /// assert_eq!(error.kind, ParseErrorKind::InvalidArc1);
+    /// assert_eq!(error.span, 2..4);
+    /// assert_eq!(error.arc, 1);
+    /// ```
+    pub fn diagnose_absolute(&self, s: &str) -> Result<(), ParseDiagnostic> {
+        self.diagnose(s, true)
+    }
+
+    /// Explains why [ParseOptions::parse_relative] fails on `s`, see [ParseOptions::diagnose_absolute]
+    pub fn diagnose_relative(&self, s: &str) -> Result<(), ParseDiagnostic> {
+        self.diagnose(s, false)
+    }
+
+    fn diagnose(&self, s: &str, absolute: bool) -> Result<(), ParseDiagnostic> {
+        // the same path as parsing, only the error is explained afterwards
+        let result = if absolute {
+            self.parse_absolute(s).and_then(|(_, arcs)| check_arcs(arcs))
+        } else {
+            self.parse_relative(s).and_then(check_arcs)
+        };
+        result.map_err(|error| self.explain(error, s))
+    }
+
+    /// Converts an error of [ParseOptions::parse_absolute] or [ParseOptions::parse_relative] of `s`
+    fn explain(&self, error: OidParsingError, s: &str) -> ParseDiagnostic {
+        let (trimmed, start) = self.trim(s);
+        let end = start + trimmed.len();
+        // arcs are counted after the leading dot
+        let body = if trimmed.starts_with('.') { start + 1 } else { start };
+        let arc_at = |pos: usize| s.get(body..pos).map_or(0, |before| before.matches('.').count());
+
+        match error {
+            OidParsingError::UnexpectedLeadingDot(pos) => {
+                ParseDiagnostic::new(ParseErrorKind::UnexpectedLeadingDot, pos..pos + 1, 0)
+            }
+            OidParsingError::LeadingDotExpected(pos) => {
+                ParseDiagnostic::new(ParseErrorKind::MissingLeadingDot, pos..pos, 0)
+            }
+            OidParsingError::InvalidChar(pos) | OidParsingError::UrnPrefixExpected(pos) => {
+                let len = s.get(pos..).and_then(|rest| rest.chars().next()).map_or(0, char::len_utf8);
+                ParseDiagnostic::new(ParseErrorKind::InvalidChar, pos..pos + len, arc_at(pos))
+            }
+            // points at the dot before the missing arc, or at the end of empty text
+            OidParsingError::IntegerExpected(pos) if pos + 1 == end && pos >= body => {
+                ParseDiagnostic::new(ParseErrorKind::TrailingDot, pos..pos + 1, arc_at(pos) + 1)
+            }
+            OidParsingError::IntegerExpected(pos) => {
+                let pos = if pos < end { pos + 1 } else { pos };
+                ParseDiagnostic::new(ParseErrorKind::EmptyArc, pos..pos, arc_at(pos))
+            }
+            OidParsingError::LeadingZero(pos) => {
+                let len = s[pos..].bytes().take_while(u8::is_ascii_digit).count();
+                ParseDiagnostic::new(ParseErrorKind::LeadingZero, pos..pos + len, arc_at(pos))
+            }
+            OidParsingError::OverflowError(span) => {
+                let arc = arc_at(span.start);
+                ParseDiagnostic::new(ParseErrorKind::ArcOverflow, span, arc)
+            }
+            OidParsingError::InvalidArc0(span) => ParseDiagnostic::new(ParseErrorKind::InvalidArc0, span, 0),
+            OidParsingError::InvalidArc1(span) => ParseDiagnostic::new(ParseErrorKind::InvalidArc1, span, 1),
+            OidParsingError::NoArc1 => ParseDiagnostic::new(ParseErrorKind::MissingArc1, end..end, 1),
+        }
+    }
+}
+
+fn check_arcs(mut arcs: StrArcs<'_>) -> Result<(), OidParsingError> {
+    arcs.try_for_each(|arc| arc.map(|_| ()))
+}
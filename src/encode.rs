@@ -1,6 +1,18 @@
 use crate::{Arc, RelativeOid, ARC_LEN};
 
-pub fn write_b128(buf: &mut [u8; ARC_LEN], mut arc: Arc) -> &mut RelativeOid {
+pub fn write_b128(buf: &mut [u8; ARC_LEN], arc: Arc) -> &mut RelativeOid {
+    let (bytes, start) = encode_b128(arc);
+    *buf = bytes;
+    let bytes = &mut buf[start..];
+    // SAFETY: bytes were just constructed, so it is a valid (1-segment) oid
+    unsafe { RelativeOid::from_mut_bytes_unchecked(bytes) }
+}
+
+/// Encodes `arc` at the end of the buffer, returns the buffer and the index of the first byte.
+///
+/// Bytes before the returned index are zero.
+pub(crate) const fn encode_b128(mut arc: Arc) -> ([u8; ARC_LEN], usize) {
+    let mut buf = [0u8; ARC_LEN];
     let mut k = ARC_LEN - 1;
     buf[k] = (arc & 0x7f) as u8;
     arc >>= 7;
@@ -9,7 +21,15 @@ pub fn write_b128(buf: &mut [u8; ARC_LEN], mut arc: Arc) -> &mut RelativeOid {
         buf[k] = 0x80 | (arc & 0x7f) as u8;
         arc >>= 7;
     }
-    let bytes = &mut buf[k..];
-    // SAFETY: bytes were just constructed, so it is a valid (1-segment) oid
-    unsafe { RelativeOid::from_mut_bytes_unchecked(bytes) }
+    (buf, k)
+}
+
+/// Number of bytes in base-128 encoding of `arc`
+pub(crate) const fn b128_len(arc: Arc) -> usize {
+    let n_bits = Arc::BITS - arc.leading_zeros();
+    if n_bits == 0 {
+        // zero still takes one byte
+        return 1;
+    }
+    n_bits.div_ceil(7) as usize
 }
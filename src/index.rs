@@ -1,6 +1,6 @@
-use core::{mem::size_of, ops::{Index, IndexMut, RangeBounds}};
+use core::ops::{Index, IndexMut, RangeBounds};
 
-use crate::{AbsoluteOid, Arc, RelativeOid, RootOid};
+use crate::{encode::b128_len, AbsoluteOid, Arc, RelativeOid};
 
 /// [`AbsoluteOid`] is inconvenient for slicing.
 /// First byte of [`AbsoluteOid`] has different meaning from the rest.
@@ -13,6 +13,7 @@ impl AbsoluteOid {
     /// Length of the oid in bytes
     /// 
     /// Note, [`AbsoluteOid`] is **never empty**, thus `self.len() >= 1`
+    #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
         self.as_bytes().len()
     }
@@ -66,9 +67,8 @@ impl AbsoluteOid {
     /// # Safety
     /// The caller must ensure that `tail` as bytes slice is a prefix of `self.tail()`.
     pub unsafe fn with_tail_unchecked<'a>(&'a self, tail: &'a RelativeOid) -> &'a AbsoluteOid {
-        debug_assert_eq!(size_of::<RootOid>(), 1);
         // additional bytes for root
-        let len = tail.len() + 1;
+        let len = tail.len() + self.root_len();
 
         debug_assert_eq!(self.tail().as_ptr(), tail.as_ptr());
         debug_assert!(len <= self.len());
@@ -337,12 +337,3 @@ fn truncate_to_arc_boundary(oid: &RelativeOid, mut max: usize) -> (bool, &Relati
     }
 }
 
-fn b128_len(arc: Arc) -> usize {
-    match arc {
-        0..=0x7f => 1,
-        0x80..=0x3fff => 2,
-        0x4000..=0x1f_ffff => 3,
-        0x20_0000..=0xfff_ffff => 4,
-        0x1000_0000..=0xffff_ffff => 5,
-    }
-}
@@ -0,0 +1,357 @@
+//! OID internationalized resource identifiers, see X.660 and X.680
+//!
+//! `OID-IRI` is a path of Unicode labels from the root, e.g. `/ISO/Registration_Authority/19785.CBEFF`,
+//! `RELATIVE-OID-IRI` is the same path without the leading `/`.
+//! Labels are names of arcs, so numeric form requires a [LabelResolver].
+
+use core::fmt;
+
+use crate::Arc;
+
+/// Source of arcs for non-integer Unicode labels
+pub trait LabelResolver {
+    /// Arc labelled `label` under the node `parent`, empty `parent` is the root
+    ///
+    /// Integer labels and labels of the top-level arcs (`ITU-T`, `ISO`, `Joint-ISO-ITU-T`)
+    /// are resolved without asking.
+    fn resolve_label(&self, parent: &[Arc], label: &str) -> Option<Arc>;
+}
+
+impl<T: LabelResolver + ?Sized> LabelResolver for &T {
+    fn resolve_label(&self, parent: &[Arc], label: &str) -> Option<Arc> {
+        (**self).resolve_label(parent, label)
+    }
+}
+
+/// Resolves nothing, only integer and top-level labels are accepted
+impl LabelResolver for () {
+    fn resolve_label(&self, _parent: &[Arc], _label: &str) -> Option<Arc> {
+        None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum IriError {
+    /// `OID-IRI` must start with `/`, `RELATIVE-OID-IRI` must not
+    LeadingSlash,
+    /// Label at the byte position is empty or violates X.660 rules
+    InvalidLabel(usize),
+    /// Integer label at the byte position does not fit into [Arc]
+    OutOfRange(usize),
+    /// Label at the byte position is not known to the resolver
+    UnknownLabel(usize),
+    /// Absolute oid must have at least two arcs
+    NoArc1,
+    /// First two arcs do not form a valid root
+    InvalidRoot,
+    /// Encoded value is not UTF-8
+    InvalidUtf8,
+}
+
+impl fmt::Display for IriError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IriError::LeadingSlash => f.write_str("only absolute OID-IRI starts with `/`"),
+            IriError::InvalidLabel(pos) => write!(f, "invalid label at position {}", pos),
+            IriError::OutOfRange(pos) => write!(f, "arc at position {} is out of range", pos),
+            IriError::UnknownLabel(pos) => write!(f, "unknown label at position {}", pos),
+            IriError::NoArc1 => f.write_str("absolute oid must have at least two arcs"),
+            IriError::InvalidRoot => f.write_str("invalid first two arcs"),
+            IriError::InvalidUtf8 => f.write_str("OID-IRI is not valid UTF-8"),
+        }
+    }
+}
+
+impl crate::error::Error for IriError {}
+
+#[cfg(feature = "alloc")]
+mod owned {
+    use alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    };
+    use core::{convert::TryFrom, fmt, str::FromStr};
+
+    use super::{IriError, LabelResolver};
+    use crate::{
+        tlv::{decode_tlv, to_tlv, write_tlv},
+        AbsoluteOid, AbsoluteOidVec, Arc, Arc0, Arc1, BufferTooSmallError, EncodingRules,
+        RelativeOidVec, RootOid, Tag, TlvError,
+    };
+
+    /// Owned `OID-IRI`, e.g. `/ISO/Registration_Authority/19785.CBEFF`
+    ///
+    /// # Invariants
+    /// starts with `/`, followed by valid Unicode labels separated by `/`
+    /// ```
+    /// # use oid_str::OidIri;
+    /// let iri: OidIri = "/Joint-ISO-ITU-T/Example/1".parse().unwrap();
+    /// assert_eq!(iri.labels().collect::<Vec<_>>(), ["Joint-ISO-ITU-T", "Example", "1"]);
+    /// assert!("/ISO/-bad".parse::<OidIri>().is_err());
+    /// ```
+    #[derive(Clone, PartialEq, Eq, Hash)]
+    pub struct OidIri {
+        iri: String,
+    }
+
+    /// Owned `RELATIVE-OID-IRI`, e.g. `Registration_Authority/19785.CBEFF`
+    ///
+    /// # Invariants
+    /// valid Unicode labels separated by `/`
+    #[derive(Clone, PartialEq, Eq, Hash)]
+    pub struct RelativeOidIri {
+        iri: String,
+    }
+
+    impl OidIri {
+        /// `OID-IRI` with integer labels only, e.g. `/1/3/6/1`
+        pub fn from_oid(oid: &AbsoluteOid) -> OidIri {
+            let mut iri = String::new();
+            for arc in oid.arcs() {
+                iri.push('/');
+                iri.push_str(&arc.to_string());
+            }
+            OidIri { iri }
+        }
+
+        /// Labels from the root
+        pub fn labels(&self) -> core::str::Split<'_, char> {
+            self.iri[1..].split('/')
+        }
+
+        /// Numeric form, non-integer labels are resolved with `resolver`
+        ///
+        /// A label of the root, that is not a top-level arc, is looked up as a long arc
+        /// under `Joint-ISO-ITU-T`, as X.660 allows.
+        /// ```
+        /// # use oid_str::{Arc, LabelResolver, OidIri};
+        /// struct Example;
+        ///
+        /// impl LabelResolver for Example {
+        ///     fn resolve_label(&self, parent: &[Arc], label: &str) -> Option<Arc> {
+        ///         match (parent, label) {
+        ///             ([2], "Example") => Some(999),
+        ///             _ => None,
+        ///         }
+        ///     }
+        /// }
+        ///
+        /// let iri: OidIri = "/Example/1".parse().unwrap();
+        /// assert_eq!(iri.to_oid(&Example).unwrap().to_string(), "2.999.1");
+        /// ```
+        pub fn to_oid<R: LabelResolver + ?Sized>(&self, resolver: &R) -> Result<AbsoluteOidVec, IriError> {
+            let mut arcs: Vec<Arc> = Vec::new();
+            let mut pos = 1;
+            for label in self.labels() {
+                let arc = match integer_label(label, pos)? {
+                    Some(arc) => Some(arc),
+                    None if arcs.is_empty() => root_label(label),
+                    None => resolver.resolve_label(&arcs, label),
+                };
+                match arc {
+                    Some(arc) => arcs.push(arc),
+                    None if arcs.is_empty() => {
+                        let arc = resolver.resolve_label(&[2], label).ok_or(IriError::UnknownLabel(pos))?;
+                        arcs.extend_from_slice(&[2, arc]);
+                    }
+                    None => return Err(IriError::UnknownLabel(pos)),
+                }
+                pos += label.len() + 1;
+            }
+
+            if arcs.len() < 2 {
+                return Err(IriError::NoArc1);
+            }
+            let arc0 = u8::try_from(arcs[0]).ok().and_then(|arc| Arc0::new(arc).ok());
+            let arc0 = arc0.ok_or(IriError::InvalidRoot)?;
+            let arc1 = Arc1::new(arcs[1]).map_err(|_| IriError::InvalidRoot)?;
+            let root = RootOid::new(arc0, arc1).map_err(|_| IriError::InvalidRoot)?;
+            let mut oid = AbsoluteOidVec::from_root(root);
+            for &arc in &arcs[2..] {
+                oid.push(arc);
+            }
+            Ok(oid)
+        }
+    }
+
+    impl RelativeOidIri {
+        /// Labels from the parent node
+        pub fn labels(&self) -> core::str::Split<'_, char> {
+            self.iri.split('/')
+        }
+
+        /// Numeric form relative to `parent`, non-integer labels are resolved with `resolver`
+        pub fn to_relative_oid<R: LabelResolver + ?Sized>(
+            &self,
+            parent: &AbsoluteOid,
+            resolver: &R,
+        ) -> Result<RelativeOidVec, IriError> {
+            let mut path: Vec<Arc> = parent.arcs().collect();
+            let mut oid = RelativeOidVec::default();
+            let mut pos = 0;
+            for label in self.labels() {
+                let arc = match integer_label(label, pos)? {
+                    Some(arc) => arc,
+                    None => resolver.resolve_label(&path, label).ok_or(IriError::UnknownLabel(pos))?,
+                };
+                path.push(arc);
+                oid.push(arc);
+                pos += label.len() + 1;
+            }
+            Ok(oid)
+        }
+    }
+
+    macro_rules! impl_iri {
+        ($ty:ident, $tag:ident, $name:literal, $check:expr) => {
+            impl $ty {
+                pub fn as_str(&self) -> &str {
+                    &self.iri
+                }
+
+                pub fn into_string(self) -> String {
+                    self.iri
+                }
+
+                #[doc = concat!("Decode `", $name, "` TLV, returns the value and the rest of the input")]
+                pub fn from_der_tlv(bytes: &[u8]) -> Result<($ty, &[u8]), TlvError> {
+                    $ty::from_tlv(bytes, Tag::$tag, EncodingRules::Der)
+                }
+
+                #[doc = concat!("Same as [", stringify!($ty), "::from_der_tlv], but accepts non-minimal length encoding")]
+                pub fn from_ber_tlv(bytes: &[u8]) -> Result<($ty, &[u8]), TlvError> {
+                    $ty::from_tlv(bytes, Tag::$tag, EncodingRules::Ber)
+                }
+
+                /// Decode TLV with an arbitrary tag, e.g. implicitly tagged one
+                pub fn from_tlv(bytes: &[u8], tag: Tag, rules: EncodingRules) -> Result<($ty, &[u8]), TlvError> {
+                    let (value, rest) = decode_tlv(bytes, tag, rules)?;
+                    let iri = core::str::from_utf8(value).map_err(|_| IriError::InvalidUtf8)?;
+                    Ok((iri.parse()?, rest))
+                }
+
+                #[doc = concat!("Write `", $name, "` TLV into `buf`, returns the number of bytes written")]
+                pub fn write_der_tlv(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmallError> {
+                    self.write_tlv(Tag::$tag, buf)
+                }
+
+                /// Write TLV with the given tag into `buf`, returns the number of bytes written
+                pub fn write_tlv(&self, tag: Tag, buf: &mut [u8]) -> Result<usize, BufferTooSmallError> {
+                    write_tlv(tag, self.iri.as_bytes(), buf)
+                }
+
+                pub fn to_der_tlv(&self) -> Vec<u8> {
+                    self.to_tlv(Tag::$tag)
+                }
+
+                pub fn to_tlv(&self, tag: Tag) -> Vec<u8> {
+                    to_tlv(tag, self.iri.as_bytes())
+                }
+            }
+
+            impl FromStr for $ty {
+                type Err = IriError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    $check(s)?;
+                    Ok($ty { iri: s.into() })
+                }
+            }
+
+            impl TryFrom<String> for $ty {
+                type Error = IriError;
+
+                fn try_from(s: String) -> Result<Self, Self::Error> {
+                    $check(&s)?;
+                    Ok($ty { iri: s })
+                }
+            }
+
+            impl fmt::Display for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str(&self.iri)
+                }
+            }
+
+            impl fmt::Debug for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(self, f)
+                }
+            }
+        };
+    }
+
+    impl_iri!(OidIri, OID_IRI, "OID-IRI", check_absolute);
+    impl_iri!(RelativeOidIri, RELATIVE_OID_IRI, "RELATIVE-OID-IRI", check_relative);
+
+    fn check_absolute(s: &str) -> Result<(), IriError> {
+        match s.strip_prefix('/') {
+            Some(labels) => check_labels(labels, 1),
+            None => Err(IriError::LeadingSlash),
+        }
+    }
+
+    fn check_relative(s: &str) -> Result<(), IriError> {
+        if s.starts_with('/') {
+            return Err(IriError::LeadingSlash);
+        }
+        check_labels(s, 0)
+    }
+
+    /// Checks `/`-separated labels, `start` is the position of `labels` in the whole IRI
+    fn check_labels(labels: &str, start: usize) -> Result<(), IriError> {
+        let mut pos = start;
+        for label in labels.split('/') {
+            if !is_valid_label(label) {
+                return Err(IriError::InvalidLabel(pos));
+            }
+            pos += label.len() + 1;
+        }
+        Ok(())
+    }
+
+    /// Unicode label rules of X.660, clause 7.5
+    fn is_valid_label(label: &str) -> bool {
+        if label.is_empty() {
+            return false;
+        }
+        if label.bytes().all(|c| c.is_ascii_digit()) {
+            return label == "0" || !label.starts_with('0');
+        }
+        // hyphens in the third and the fourth positions are reserved, as in IDNA `xn--`
+        let mut chars = label.chars().skip(2);
+        let reserved = chars.next() == Some('-') && chars.next() == Some('-');
+        label.chars().all(is_iunreserved) && !label.starts_with('-') && !label.ends_with('-') && !reserved
+    }
+
+    /// `iunreserved` of RFC 3987
+    fn is_iunreserved(c: char) -> bool {
+        match c as u32 {
+            0..=0x7f => c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | '~'),
+            0xa0..=0xd7ff | 0xf900..=0xfdcf | 0xfdf0..=0xffef | 0xe1000..=0xefffd => true,
+            // planes 1 to 13, except the last two code points of each one
+            code @ 0x10000..=0xdffff => code & 0xffff < 0xfffe,
+            _ => false,
+        }
+    }
+
+    /// Value of an integer label, `None` for non-integer ones
+    fn integer_label(label: &str, pos: usize) -> Result<Option<Arc>, IriError> {
+        if !label.bytes().all(|c| c.is_ascii_digit()) {
+            return Ok(None);
+        }
+        label.parse().map(Some).map_err(|_| IriError::OutOfRange(pos))
+    }
+
+    fn root_label(label: &str) -> Option<Arc> {
+        match label {
+            "ITU-T" => Some(0),
+            "ISO" => Some(1),
+            "Joint-ISO-ITU-T" => Some(2),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub use owned::{OidIri, RelativeOidIri};
@@ -1,9 +1,13 @@
-use core::convert::TryFrom;
+use core::ops::Range;
 
-use crate::{borrowed::MAX_ROOT_BYTE, AbsoluteOid, Arc, Arc0, Arc1, RelativeOid, RootOid};
+use crate::{
+    borrowed::{next_b128_error, root_len, subidentifier_error, to_position},
+    AbsoluteOid, Arc, Arc0, Arc1, B128Error, OidDecodingError, RelativeOid,
+    RootError, RootOid,
+};
 
 impl AbsoluteOid {
-    pub fn arcs(&self) -> AbsoluteArcs {
+    pub fn arcs(&self) -> AbsoluteArcs<'_> {
         AbsoluteArcs {
             root: self.root().arcs(),
             tail: self.tail().arcs(),
@@ -12,22 +16,29 @@ impl AbsoluteOid {
 }
 
 impl RootOid {
-    pub const fn arcs(&self) -> RootArcs {
+    pub fn arcs(&self) -> RootArcs {
+        let (arc0, arc1) = self.into_arcs();
         RootArcs {
-            byte: self.into_u8(),
+            arc0: Some(arc0 as Arc),
+            arc1: Some(arc1.as_arc()),
         }
     }
 
     pub fn into_arcs(self) -> (Arc0, Arc1) {
-        let byte = self.into_u8();
-        let arc0 = Arc0::try_from(byte / 40).unwrap();
-        let arc1 = Arc1::try_from(byte % 40).unwrap();
+        let value = self.subidentifier();
+        let (arc0, arc1) = match value {
+            0..=39 => (Arc0::ItuT, value),
+            40..=79 => (Arc0::Iso, value - 40),
+            _ => (Arc0::JointIsoItuT, value - 80),
+        };
+        // SAFETY: `value <= Arc::MAX`, so `value - 80 <= MAX_ARC1`
+        let arc1 = unsafe { Arc1::new_unchecked(arc1) };
         (arc0, arc1)
     }
 }
 
 impl RelativeOid {
-    pub fn arcs(&self) -> RelativeArcs {
+    pub fn arcs(&self) -> RelativeArcs<'_> {
         RelativeArcs {
             bytes: self.as_bytes().iter(),
         }
@@ -35,9 +46,8 @@ impl RelativeOid {
 }
 
 pub struct RootArcs {
-    /// Value higher than `119(0x77)` means `segment0` has been consumed,
-    /// Value `0xff` means all bits has been consumed
-    byte: u8,
+    arc0: Option<Arc>,
+    arc1: Option<Arc>,
 }
 
 pub struct AbsoluteArcs<'a> {
@@ -53,28 +63,7 @@ impl core::iter::Iterator for RootArcs {
     type Item = Arc;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.byte == 0xff {
-            return None;
-        }
-        let arc = if self.byte > MAX_ROOT_BYTE {
-            let byte = core::mem::replace(&mut self.byte, 0xff);
-            byte % 40
-        } else {
-            let arc = self.byte / 40;
-            let old_byte = self.byte;
-            // we need to preserve `segment1 = byte % 40`, and we do not want to overflow
-            self.byte += (4 - arc) * 40;
-
-            debug_assert_eq!(
-                old_byte % 40,
-                self.byte % 40,
-                "segment1 was changed when consuming segment0"
-            );
-            debug_assert!(self.byte > MAX_ROOT_BYTE, "failed to consume segment0");
-
-            arc
-        };
-        Some(arc as Arc)
+        self.arc0.take().or_else(|| self.arc1.take())
     }
 }
 
@@ -83,7 +72,7 @@ impl core::iter::Iterator for RelativeArcs<'_> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let mut accumulator = 0;
-        while let Some(&byte) = self.bytes.next() {
+        for &byte in &mut self.bytes {
             if !b128_eat_byte(&mut accumulator, byte) {
                 return Some(accumulator);
             }
@@ -111,3 +100,72 @@ fn b128_eat_byte(accumulator: &mut Arc, byte: u8) -> bool {
 
     (byte & 0x80) != 0
 }
+
+/// Iterator over errors in a [RelativeOid], see [RelativeOid::errors]
+pub struct RelativeErrors<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> RelativeErrors<'a> {
+    pub(crate) fn new(bytes: &'a [u8], pos: usize) -> RelativeErrors<'a> {
+        RelativeErrors { bytes, pos }
+    }
+}
+
+impl core::iter::Iterator for RelativeErrors<'_> {
+    type Item = (B128Error, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pos < self.bytes.len() {
+            let start = self.pos;
+            let (kind, end) = subidentifier_error(self.bytes, start, true);
+            self.pos = end;
+            if let Some(kind) = kind {
+                let error = B128Error {
+                    kind,
+                    pos: to_position(start),
+                };
+                return Some((error, start..end));
+            }
+        }
+        None
+    }
+}
+
+/// Iterator over errors in an [AbsoluteOid], see [AbsoluteOid::errors]
+pub struct AbsoluteErrors<'a> {
+    root: Option<(OidDecodingError, Range<usize>)>,
+    tail: RelativeErrors<'a>,
+}
+
+impl<'a> AbsoluteErrors<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> AbsoluteErrors<'a> {
+        if bytes.is_empty() {
+            return AbsoluteErrors {
+                root: Some((OidDecodingError::Empty, 0..0)),
+                tail: RelativeErrors::new(bytes, 0),
+            };
+        }
+        // same as in `AbsoluteOid::check_bytes`
+        let root_len = root_len(bytes).unwrap_or(bytes.len());
+        let root = next_b128_error(&bytes[..root_len])
+            .map(|error| (OidDecodingError::Root(RootError(Some(error))), 0..root_len));
+        AbsoluteErrors {
+            root,
+            tail: RelativeErrors::new(bytes, root_len),
+        }
+    }
+}
+
+impl core::iter::Iterator for AbsoluteErrors<'_> {
+    type Item = (OidDecodingError, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(root) = self.root.take() {
+            return Some(root);
+        }
+        let (error, range) = self.tail.next()?;
+        Some((OidDecodingError::Base128(error), range))
+    }
+}
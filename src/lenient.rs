@@ -0,0 +1,169 @@
+//! Lenient BER decoding, that accepts subidentifiers with redundant leading `0x80` bytes
+//!
+//! X.690 forbids such padding, but some encoders produce it anyway.
+//! Padding is removed, so the result is always in canonical form.
+
+use crate::{
+    borrowed::{subidentifier_error, to_position},
+    AbsoluteOid, B128Error, B128ErrorKind, OidDecodingError, RelativeOid, RootError,
+};
+
+impl RelativeOid {
+    /// Decodes `bytes`, removing redundant leading `0x80` bytes from subidentifiers
+    ///
+    /// Canonical encoding is written to the beginning of `bytes`,
+    /// the returned flag tells whether any padding was removed.
+    /// On error `bytes` are left unchanged.
+    /// ```
+    /// # use oid_str::RelativeOid;
+    /// let mut bytes = *b"\x80\x80\x86\x48\x01";
+    /// let (oid, normalized) = RelativeOid::normalize_ber(&mut bytes).unwrap();
+    /// assert_eq!(oid.as_bytes(), b"\x86\x48\x01");
+    /// assert!(normalized);
+    /// ```
+    pub fn normalize_ber(bytes: &mut [u8]) -> Result<(&mut RelativeOid, bool), B128Error> {
+        let normalized = check_padded(bytes, 0)?;
+        let len = if normalized { remove_padding(bytes, 0) } else { bytes.len() };
+        // SAFETY: `check_padded` ensures that every subidentifier is valid once padding is removed
+        let oid = unsafe { RelativeOid::from_mut_bytes_unchecked(&mut bytes[..len]) };
+        Ok((oid, normalized))
+    }
+}
+
+impl AbsoluteOid {
+    /// Decodes `bytes`, removing redundant leading `0x80` bytes from subidentifiers
+    ///
+    /// Same as [RelativeOid::normalize_ber], the first subidentifier is normalized as well.
+    /// ```
+    /// # use oid_str::AbsoluteOid;
+    /// let mut bytes = *b"\x2b\x06\x80\x01";
+    /// let (oid, normalized) = AbsoluteOid::normalize_ber(&mut bytes).unwrap();
+    /// assert_eq!(oid.to_string(), "1.3.6.1");
+    /// assert!(normalized);
+    /// ```
+    pub fn normalize_ber(bytes: &mut [u8]) -> Result<(&mut AbsoluteOid, bool), OidDecodingError> {
+        if bytes.is_empty() {
+            return Err(OidDecodingError::Empty);
+        }
+        let (kind, root_digits, root_end) = padded_subidentifier(bytes, 0);
+        if let Some(kind) = kind {
+            return Err(OidDecodingError::Root(RootError(Some(B128Error { kind, pos: 0 }))));
+        }
+        let tail_padded = check_padded(bytes, root_end)?;
+        let normalized = root_digits != 0 || tail_padded;
+        let len = if normalized { remove_padding(bytes, 0) } else { bytes.len() };
+        // SAFETY: `bytes` start with a valid root followed by valid subidentifiers,
+        // once padding is removed
+        let oid = unsafe { AbsoluteOid::from_mut_bytes_unchecked(&mut bytes[..len]) };
+        Ok((oid, normalized))
+    }
+}
+
+/// Checks subidentifiers starting at `pos`, returns whether any of them is padded
+fn check_padded(bytes: &[u8], mut pos: usize) -> Result<bool, B128Error> {
+    let mut padded = false;
+    while pos < bytes.len() {
+        let (kind, digits, end) = padded_subidentifier(bytes, pos);
+        if let Some(kind) = kind {
+            return Err(B128Error {
+                kind,
+                pos: to_position(pos),
+            });
+        }
+        padded |= digits != pos;
+        pos = end;
+    }
+    Ok(padded)
+}
+
+/// Checks a single subidentifier starting at `start`, ignoring its padding
+///
+/// Returns the first error, position of the first significant byte and position after the subidentifier
+fn padded_subidentifier(bytes: &[u8], start: usize) -> (Option<B128ErrorKind>, usize, usize) {
+    let mut digits = start;
+    while digits < bytes.len() && bytes[digits] == 0x80 {
+        digits += 1;
+    }
+    if digits == bytes.len() {
+        return (Some(B128ErrorKind::Unfinished), digits, digits);
+    }
+    let (kind, end) = subidentifier_error(bytes, digits, true);
+    (kind, digits, end)
+}
+
+/// Moves significant bytes of valid subidentifiers to the front, returns the new length
+fn remove_padding(bytes: &mut [u8], mut pos: usize) -> usize {
+    let mut len = pos;
+    while pos < bytes.len() {
+        let (_, digits, end) = padded_subidentifier(bytes, pos);
+        bytes.copy_within(digits..end, len);
+        len += end - digits;
+        pos = end;
+    }
+    len
+}
+
+#[cfg(feature = "alloc")]
+mod owned {
+    use crate::{
+        AbsoluteOid, AbsoluteOidVec, B128Error, OidDecodingError, RelativeOid, RelativeOidVec, SmallAbsoluteOid,
+        SmallRelativeOid, SMALL_OID_INLINE_LEN,
+    };
+
+    impl RelativeOidVec {
+        /// Owned version of [RelativeOid::normalize_ber]
+        pub fn from_ber_lenient(bytes: &[u8]) -> Result<(RelativeOidVec, bool), B128Error> {
+            let mut bytes = bytes.to_vec();
+            let (oid, normalized) = RelativeOid::normalize_ber(&mut bytes)?;
+            Ok((RelativeOidVec::from_oid(oid), normalized))
+        }
+    }
+
+    impl AbsoluteOidVec {
+        /// Owned version of [AbsoluteOid::normalize_ber]
+        pub fn from_ber_lenient(bytes: &[u8]) -> Result<(AbsoluteOidVec, bool), OidDecodingError> {
+            let mut bytes = bytes.to_vec();
+            let (oid, normalized) = AbsoluteOid::normalize_ber(&mut bytes)?;
+            Ok((AbsoluteOidVec::from_oid(oid), normalized))
+        }
+    }
+
+    impl SmallRelativeOid {
+        /// Same as [RelativeOidVec::from_ber_lenient], but doesn't allocate for short input
+        pub fn from_ber_lenient(bytes: &[u8]) -> Result<(SmallRelativeOid, bool), B128Error> {
+            with_copy(bytes, |bytes| {
+                let (oid, normalized) = RelativeOid::normalize_ber(bytes)?;
+                Ok((SmallRelativeOid::from_oid(oid), normalized))
+            })
+        }
+    }
+
+    impl SmallAbsoluteOid {
+        /// Same as [AbsoluteOidVec::from_ber_lenient], but doesn't allocate for short input
+        /// ```
+        /// # use oid_str::SmallAbsoluteOid;
+        /// let (oid, normalized) = SmallAbsoluteOid::from_ber_lenient(b"\x2b\x06\x80\x01").unwrap();
+        /// assert_eq!(oid.to_string(), "1.3.6.1");
+        /// assert!(normalized);
+        /// assert!(oid.is_inline());
+        /// ```
+        pub fn from_ber_lenient(bytes: &[u8]) -> Result<(SmallAbsoluteOid, bool), OidDecodingError> {
+            with_copy(bytes, |bytes| {
+                let (oid, normalized) = AbsoluteOid::normalize_ber(bytes)?;
+                Ok((SmallAbsoluteOid::from_oid(oid), normalized))
+            })
+        }
+    }
+
+    /// Calls `f` with a mutable copy of `bytes`, allocates only if they don't fit inline
+    fn with_copy<T>(bytes: &[u8], f: impl FnOnce(&mut [u8]) -> T) -> T {
+        let mut buffer = [0; SMALL_OID_INLINE_LEN];
+        match buffer.get_mut(..bytes.len()) {
+            Some(buffer) => {
+                buffer.copy_from_slice(bytes);
+                f(buffer)
+            }
+            None => f(&mut bytes.to_vec()),
+        }
+    }
+}
@@ -36,7 +36,7 @@
 //! assert_eq!(oid.to_string(), "1.3.6.1.2.1.1");
 //! ```
 //! 
-//! Absolute oids treat their first byte differently from the rest
+//! Absolute oids treat their first subidentifier differently from the rest
 //! ```rust
 //! # use oid_str::{AbsoluteOid, RelativeOid};
 //! 
@@ -45,9 +45,14 @@
 //! 
 //! let relative = RelativeOid::from_bytes(b"\x2b\x06\x2b\x01").unwrap();
 //! assert_eq!(relative.to_string(), ".43.6.43.1");
+//!
+//! // Under joint-iso-itu-t(2) the second arc is unbounded, so the first subidentifier
+//! // might take several bytes
+//! let absolute = AbsoluteOid::from_bytes(b"\x88\x37\x01").unwrap();
+//! assert_eq!(absolute.to_string(), "2.999.1");
 //! ```
 //! 
-//! In fact, absolute oid can be thought of as (first byte, rest as RelativeOid)
+//! In fact, absolute oid can be thought of as (first subidentifier, rest as RelativeOid)
 //! ```rust
 //! # use oid_str::{AbsoluteOid, AbsoluteOidVec, RelativeOid};
 //! 
@@ -61,16 +66,108 @@
 //! assert_eq!(&*prefix, whole);
 //! ```
 //! 
+//! Constant oids are built with [oid!] and [relative_oid!] macros,
+//! text is validated and encoded at compile time
+//! ```rust
+//! # use oid_str::{oid, StaticAbsoluteOid};
+//!
+//! const ID_PKIX: StaticAbsoluteOid<'static> = oid!("1.3.6.1.5.5.7");
+//! assert_eq!(ID_PKIX.as_bytes(), b"\x2b\x06\x01\x05\x05\x07");
+//! ```
+//!
+//! ASN.1 value notation is parsed with [AbsoluteOidVec::parse_asn1]
+//! ```rust
+//! # use oid_str::AbsoluteOidVec;
+//!
+//! let oid = AbsoluteOidVec::parse_asn1("{ iso(1) identified-organization(3) dod(6) 1 }").unwrap();
+//! assert_eq!(oid.to_string(), "1.3.6.1");
+//! ```
+//!
+//! Oids are ordered by their arcs, without decoding
+//! ```rust
+//! # use std::collections::BTreeSet;
+//! # use oid_str::AbsoluteOidVec;
+//!
+//! let set: BTreeSet<AbsoluteOidVec> = ["1.3.6.1.10", "1.3.6.1.2", "2.999", "1.2"]
+//!     .iter()
+//!     .map(|s| s.parse().unwrap())
+//!     .collect();
+//! let sorted: Vec<String> = set.iter().map(|oid| oid.to_string()).collect();
+//! assert_eq!(sorted, ["1.2", "1.3.6.1.2", "1.3.6.1.10", "2.999"]);
+//! ```
+//!
+//! # TLV encoding
+//!
+//! `from_bytes` works with content octets only.
+//! Whole tag-length-value encoding is handled by `from_der_tlv`/`write_der_tlv` and friends,
+//! see [Tag] for implicitly tagged values.
+//! ```rust
+//! # use oid_str::AbsoluteOid;
+//!
+//! let (oid, rest) = AbsoluteOid::from_der_tlv(b"\x06\x03\x2b\x06\x01").unwrap();
+//! assert_eq!(oid.to_string(), "1.3.6.1");
+//! assert!(rest.is_empty());
+//! assert_eq!(oid.to_der_tlv(), b"\x06\x03\x2b\x06\x01");
+//! ```
+//!
+//! `OID-IRI` and `RELATIVE-OID-IRI` values (e.g. `/ISO/Registration_Authority/19785.CBEFF`)
+//! are stored in [OidIri] and [RelativeOidIri], and converted to numeric form with a [LabelResolver].
+//!
+//! # Arc width
+//!
+//! Arcs are decoded into [Arc], which is `u32` by default.
+//! Any encoded arc that does not fit into [Arc] is rejected on construction.
+//!
+//! Wider arcs (e.g. UUID-based oids under `2.25`) can be enabled with
+//! `arc-u64` or `arc-u128` features. Validation, iteration, encoding,
+//! parsing and formatting all follow the selected width.
+//!
+//! Arcs of any size are supported by [UnboundedRelativeOid] and [UnboundedAbsoluteOid].
+//! Their arcs are yielded as [BigArc], a view into raw base-128 bytes.
+//! Formatting and parsing of such oids requires `alloc` feature.
+//! ```rust
+//! # use oid_str::{UnboundedAbsoluteOid, UnboundedAbsoluteOidVec};
+//!
+//! // the last arc does not fit into 128 bits
+//! let text = "1.3.6.1.4.1.1461501637330902918203684832716283019655932542976";
+//! let oid: UnboundedAbsoluteOidVec = text.parse().unwrap();
+//! assert_eq!(oid.to_string(), text);
+//!
+//! let decoded = UnboundedAbsoluteOid::from_bytes(oid.as_bytes()).unwrap();
+//! assert!(decoded.as_bounded().is_err());
+//! ```
+//!
+//! # Integrations
+//!
+//! `serde` feature implements `Serialize` and `Deserialize`.
+//! Human-readable formats use dotted text, binary formats use BER content bytes.
+//!
+//! `der` feature implements traits of RustCrypto `der` crate,
+//! and conversions from and to `const_oid::ObjectIdentifier`.
+//!
+//! # Registry
+//!
+//! `registry` feature adds [registry] module with well-known oids
+//! (X.500, X.509, PKIX, PKCS, NIST, EC curves, SNMP) and lookups by oid and by name.
+//! With it `{:#}` annotates known arcs, e.g. `iso(1).member-body(2).us(840).rsadsi(113549)`.
+//! Other name tables can be plugged with [AbsoluteOid::display_with].
+//!
 //! # No-std support
 //! 
 //! Owned [AbsoluteOidVec] and [RelativeOidVec] require global allocator
 //! and are gated behind `alloc` feature(enabled by default).
+//! So do [SmallAbsoluteOid] and [SmallRelativeOid], that only allocate for long oids.
 //! 
 //! Everything else is expected to be working without allocator.
+//! [AbsoluteOidArray] and [RelativeOidArray] are owned oids of fixed capacity for such targets.
+//!
+//! Error types implement `core::error::Error`, thus the minimum supported Rust version is 1.81.
 //! 
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 mod root;
 mod borrowed;
@@ -78,24 +175,94 @@ mod iter;
 mod encode;
 #[cfg(feature = "alloc")]
 mod owned;
+mod array;
 mod static_ref;
 mod str;
 mod reference_conversions;
 mod index;
 mod ops;
+mod unbounded;
+mod tlv;
+mod macros;
+mod cmp;
+mod lenient;
+mod named;
+mod asn1;
+mod iri;
+mod diagnostic;
+#[cfg(feature = "alloc")]
+mod map;
+#[cfg(feature = "alloc")]
+mod set;
+#[cfg(feature = "alloc")]
+mod small;
+#[cfg(feature = "registry")]
+mod phf;
+#[cfg(feature = "registry")]
+pub mod registry;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "der")]
+mod der_impls;
 
-pub use self::str::{parse_absolute, parse_relative, OidParsingError};
+pub use self::str::{
+    parse_absolute, parse_relative, parse_urn, LeadingDot, OidParsingError, ParseIntoError, ParseOptions,
+    Urn,
+};
 pub use borrowed::{
     AbsoluteOid, B128Error, B128ErrorKind, OidDecodingError, RelativeOid, RootError, RootOid,
     MAX_ROOT_BYTE,
 };
 pub use encode::write_b128;
-pub use iter::{AbsoluteArcs, RelativeArcs, RootArcs};
+pub use array::{AbsoluteOidArray, RelativeOidArray};
+pub use asn1::{Asn1ErrorKind, Asn1ParseError, OidResolver};
+pub use diagnostic::{ParseDiagnostic, ParseErrorKind, Render};
+pub use named::{DisplayWith, NameTable};
+pub use iri::{IriError, LabelResolver};
+#[cfg(feature = "alloc")]
+pub use iri::{OidIri, RelativeOidIri};
+pub use iter::{AbsoluteArcs, AbsoluteErrors, RelativeArcs, RelativeErrors, RootArcs};
+#[cfg(feature = "alloc")]
+pub use map::{OidMap, OidMapIter, OidSubtree};
+#[cfg(feature = "alloc")]
+pub use set::{OidSet, OidSetIter};
+#[cfg(feature = "alloc")]
+pub use small::{SmallAbsoluteOid, SmallRelativeOid, SMALL_OID_INLINE_LEN};
 #[cfg(feature = "alloc")]
 pub use owned::{AbsoluteOidVec, RelativeOidVec};
-pub use root::{Arc0, Arc1};
+pub use root::{Arc0, Arc1, IllegalArc1Error, IllegalRootNodeError, MAX_ARC1};
 pub use static_ref::{StaticAbsoluteOid, StaticRelativeOid};
+pub use tlv::{BufferTooSmallError, EncodingRules, Tag, TagClass, TlvError};
+pub use unbounded::{BigArc, BigArcs, UnboundedAbsoluteOid, UnboundedRelativeOid};
+#[cfg(feature = "alloc")]
+pub use unbounded::{UnboundedAbsoluteOidVec, UnboundedRelativeOidVec};
 
-pub type Arc = u32;
+/// Value of a single arc
+///
+/// The width is selected with `arc-u64` and `arc-u128` features,
+/// if both are enabled the widest one wins. Default is `u32`.
+pub type Arc = arc_width::Arc;
 pub type Position = u16;
-pub const ARC_LEN: usize = (core::mem::size_of::<Arc>() * 8 + 6) / 7;
+pub const ARC_LEN: usize = (core::mem::size_of::<Arc>() * 8).div_ceil(7);
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::macros::{absolute_len, encode_absolute, encode_relative, relative_len};
+}
+
+mod error {
+    // `std::error::Error` is a re-export of `core::error::Error`
+    #[cfg(not(feature = "std"))]
+    pub use core::error::Error;
+    #[cfg(feature = "std")]
+    pub use std::error::Error;
+}
+
+mod arc_width {
+    #[cfg(not(any(feature = "arc-u64", feature = "arc-u128")))]
+    pub type Arc = u32;
+    #[cfg(all(feature = "arc-u64", not(feature = "arc-u128")))]
+    pub type Arc = u64;
+    #[cfg(feature = "arc-u128")]
+    pub type Arc = u128;
+}
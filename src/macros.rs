@@ -0,0 +1,210 @@
+//! Compile time construction of oids from text
+//!
+//! Text is parsed and encoded in `const fn`s below,
+//! invalid input causes a panic during const evaluation, which fails the build.
+
+use crate::{
+    encode::{b128_len, encode_b128},
+    Arc, ARC_LEN, MAX_ARC1,
+};
+
+/// [StaticAbsoluteOid](crate::StaticAbsoluteOid) from dotted text, checked at compile time
+///
+/// Accepts the same format as [ParseOptions::new](crate::ParseOptions::new), leading zeros are rejected.
+///
+/// # Examples
+/// ```
+/// use oid_str::{oid, StaticAbsoluteOid};
+///
+/// const SHA256_WITH_RSA: StaticAbsoluteOid<'static> = oid!("1.2.840.113549.1.1.11");
+/// assert_eq!(SHA256_WITH_RSA.as_bytes(), b"\x2a\x86\x48\x86\xf7\x0d\x01\x01\x0b");
+///
+/// static JOINT: StaticAbsoluteOid<'static> = oid!("2.999.1");
+/// assert_eq!(JOINT.to_string(), "2.999.1");
+/// ```
+///
+/// Invalid input fails the build
+/// ```compile_fail
+/// # use oid_str::{oid, StaticAbsoluteOid};
+/// const INVALID: StaticAbsoluteOid<'static> = oid!("1.40.1");
+/// ```
+/// ```compile_fail
+/// # use oid_str::{oid, StaticAbsoluteOid};
+/// const LEADING_ZERO: StaticAbsoluteOid<'static> = oid!("1.2.0840");
+/// ```
+#[macro_export]
+macro_rules! oid {
+    ($s:expr) => {{
+        const __OID_LEN: usize = $crate::__private::absolute_len($s);
+        const __OID_BYTES: [u8; __OID_LEN] = $crate::__private::encode_absolute($s);
+        const __OID: $crate::StaticAbsoluteOid<'static> =
+            $crate::StaticAbsoluteOid::from_bytes_const(&__OID_BYTES);
+        __OID
+    }};
+}
+
+/// [StaticRelativeOid](crate::StaticRelativeOid) from dotted text, checked at compile time
+///
+/// Accepts the same format as [ParseOptions::new](crate::ParseOptions::new), leading zeros are rejected.
+///
+/// # Examples
+/// ```
+/// use oid_str::{relative_oid, StaticRelativeOid};
+///
+/// const SUFFIX: StaticRelativeOid<'static> = relative_oid!(".840.1");
+/// assert_eq!(SUFFIX.as_bytes(), b"\x86\x48\x01");
+/// ```
+///
+/// Invalid input fails the build
+/// ```compile_fail
+/// # use oid_str::{relative_oid, StaticRelativeOid};
+/// const INVALID: StaticRelativeOid<'static> = relative_oid!(".1..2");
+/// ```
+/// ```compile_fail
+/// # use oid_str::{relative_oid, StaticRelativeOid};
+/// const LEADING_ZERO: StaticRelativeOid<'static> = relative_oid!(".01");
+/// ```
+#[macro_export]
+macro_rules! relative_oid {
+    ($s:expr) => {{
+        const __OID_LEN: usize = $crate::__private::relative_len($s);
+        const __OID_BYTES: [u8; __OID_LEN] = $crate::__private::encode_relative($s);
+        const __OID: $crate::StaticRelativeOid<'static> =
+            $crate::StaticRelativeOid::from_bytes_const(&__OID_BYTES);
+        __OID
+    }};
+}
+
+pub const fn absolute_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let (root, pos) = parse_root(bytes);
+    b128_len(root) + relative_len_at(bytes, pos)
+}
+
+pub const fn encode_absolute<const N: usize>(s: &str) -> [u8; N] {
+    let bytes = s.as_bytes();
+    let (root, pos) = parse_root(bytes);
+    let (buf, len) = write_arc([0; N], 0, root);
+    encode_relative_at(bytes, pos, buf, len)
+}
+
+pub const fn relative_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    relative_len_at(bytes, skip_leading_dot(bytes))
+}
+
+pub const fn encode_relative<const N: usize>(s: &str) -> [u8; N] {
+    let bytes = s.as_bytes();
+    encode_relative_at(bytes, skip_leading_dot(bytes), [0; N], 0)
+}
+
+/// Parses first two arcs, returns root subidentifier and position of the next arc
+const fn parse_root(bytes: &[u8]) -> (Arc, usize) {
+    let pos = skip_leading_dot(bytes);
+    if pos == bytes.len() {
+        panic!("oid must not be empty");
+    }
+    let (arc0, pos) = parse_arc(bytes, pos);
+    if arc0 > 2 {
+        panic!("first arc must be one of 0, 1, 2");
+    }
+    let pos = skip_separator(bytes, pos);
+    if pos == bytes.len() {
+        panic!("oid must contain at least two arcs");
+    }
+    let (arc1, pos) = parse_arc(bytes, pos);
+    if arc1 >= 40 && arc0 != 2 {
+        panic!("second arc must be less than 40, unless the first arc is 2");
+    }
+    if arc1 > MAX_ARC1 {
+        panic!("second arc is too large");
+    }
+    (arc0 * 40 + arc1, skip_separator(bytes, pos))
+}
+
+const fn relative_len_at(bytes: &[u8], mut pos: usize) -> usize {
+    let mut len = 0;
+    while pos < bytes.len() {
+        let (arc, next) = parse_arc(bytes, pos);
+        len += b128_len(arc);
+        pos = skip_separator(bytes, next);
+    }
+    len
+}
+
+const fn encode_relative_at<const N: usize>(
+    bytes: &[u8],
+    mut pos: usize,
+    mut buf: [u8; N],
+    mut len: usize,
+) -> [u8; N] {
+    while pos < bytes.len() {
+        let (arc, next) = parse_arc(bytes, pos);
+        let written = write_arc(buf, len, arc);
+        buf = written.0;
+        len = written.1;
+        pos = skip_separator(bytes, next);
+    }
+    if len != N {
+        panic!("encoded length does not match computed length");
+    }
+    buf
+}
+
+/// Writes `arc` at `len`, returns the buffer and the new length
+const fn write_arc<const N: usize>(mut buf: [u8; N], mut len: usize, arc: Arc) -> ([u8; N], usize) {
+    let (encoded, mut k) = encode_b128(arc);
+    while k < ARC_LEN {
+        buf[len] = encoded[k];
+        len += 1;
+        k += 1;
+    }
+    (buf, len)
+}
+
+const fn skip_leading_dot(bytes: &[u8]) -> usize {
+    if !bytes.is_empty() && bytes[0] == b'.' {
+        1
+    } else {
+        0
+    }
+}
+
+/// `pos` points either at the end or at a dot, returns position of the next arc
+const fn skip_separator(bytes: &[u8], pos: usize) -> usize {
+    if pos == bytes.len() {
+        return pos;
+    }
+    if pos + 1 == bytes.len() {
+        panic!("oid must not end with a dot");
+    }
+    pos + 1
+}
+
+/// Parses a single arc at `pos`, returns the arc and position right after it
+const fn parse_arc(bytes: &[u8], mut pos: usize) -> (Arc, usize) {
+    let start = pos;
+    let mut arc: Arc = 0;
+    while pos < bytes.len() && bytes[pos] != b'.' {
+        let c = bytes[pos];
+        if !c.is_ascii_digit() {
+            panic!("oid must contain only digits and dots");
+        }
+        arc = match arc.checked_mul(10) {
+            Some(arc) => arc,
+            None => panic!("arc does not fit into `Arc`"),
+        };
+        arc = match arc.checked_add((c - b'0') as Arc) {
+            Some(arc) => arc,
+            None => panic!("arc does not fit into `Arc`"),
+        };
+        pos += 1;
+    }
+    if pos == start {
+        panic!("oid must not contain empty arcs");
+    }
+    if pos - start > 1 && bytes[start] == b'0' {
+        panic!("arc must not have leading zeros");
+    }
+    (arc, pos)
+}
@@ -0,0 +1,214 @@
+//! Ordered map keyed by oids, with prefix queries
+
+use alloc::{
+    collections::{btree_map, BTreeMap},
+    vec::Vec,
+};
+use core::{fmt, iter::FromIterator, ops::Bound};
+
+use crate::{AbsoluteOid, AbsoluteOidVec};
+
+/// Map from [AbsoluteOid] to `V`, ordered by arcs
+///
+/// Oids sharing a prefix are adjacent in arc order (see [Ord] for [AbsoluteOid]),
+/// so a whole subtree is a contiguous range of keys.
+/// Lookups borrow the key and never allocate.
+/// ```
+/// # use oid_str::{AbsoluteOidVec, OidMap};
+/// let mut map = OidMap::new();
+/// let mib2: AbsoluteOidVec = "1.3.6.1.2.1".parse().unwrap();
+/// let sys_descr: AbsoluteOidVec = "1.3.6.1.2.1.1.1.0".parse().unwrap();
+/// map.insert(&mib2, "mib-2");
+/// map.insert(&sys_descr, "sysDescr");
+///
+/// let query: AbsoluteOidVec = "1.3.6.1.2.1.2.2".parse().unwrap();
+/// let (prefix, name) = map.longest_prefix(&query).unwrap();
+/// assert_eq!(prefix, &*mib2);
+/// assert_eq!(*name, "mib-2");
+///
+/// let (next, _) = map.get_next(&mib2).unwrap();
+/// assert_eq!(next, &*sys_descr);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct OidMap<V> {
+    map: BTreeMap<AbsoluteOidVec, V>,
+}
+
+impl<V> Default for OidMap<V> {
+    fn default() -> Self {
+        OidMap::new()
+    }
+}
+
+impl<V> OidMap<V> {
+    pub fn new() -> OidMap<V> {
+        OidMap { map: BTreeMap::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// Inserts a value, returning the previous one
+    ///
+    /// The key is only copied, if it is not in the map yet.
+    pub fn insert(&mut self, key: &AbsoluteOid, value: V) -> Option<V> {
+        match self.map.get_mut(key) {
+            Some(old) => Some(core::mem::replace(old, value)),
+            None => self.map.insert(AbsoluteOidVec::from_oid(key), value),
+        }
+    }
+
+    pub fn get(&self, key: &AbsoluteOid) -> Option<&V> {
+        self.map.get(key)
+    }
+
+    pub fn get_mut(&mut self, key: &AbsoluteOid) -> Option<&mut V> {
+        self.map.get_mut(key)
+    }
+
+    pub fn contains_key(&self, key: &AbsoluteOid) -> bool {
+        self.map.contains_key(key)
+    }
+
+    pub fn remove(&mut self, key: &AbsoluteOid) -> Option<V> {
+        self.map.remove(key)
+    }
+
+    /// The longest key, that is a prefix of `oid` (including `oid` itself)
+    pub fn longest_prefix<'a>(&'a self, oid: &AbsoluteOid) -> Option<(&'a AbsoluteOid, &'a V)> {
+        let tail = oid.tail();
+        let bytes = tail.as_bytes();
+        // prefixes end at arc boundaries, i.e. after a terminating byte
+        (0..=bytes.len())
+            .rev()
+            .filter(|&end| end == 0 || bytes[end - 1] < 0x80)
+            .find_map(|end| self.map.get_key_value(oid.with_tail(&tail[..end])))
+            .map(|(key, value)| (key.as_oid(), value))
+    }
+
+    /// All entries, whose keys start with `prefix` (including `prefix` itself), in arc order
+    pub fn subtree<'a>(&'a self, prefix: &'a AbsoluteOid) -> OidSubtree<'a, V> {
+        OidSubtree {
+            range: self.map.range::<AbsoluteOid, _>((Bound::Included(prefix), Bound::Unbounded)),
+            prefix,
+        }
+    }
+
+    /// Removes all entries, whose keys start with `prefix`, and returns them
+    ///
+    /// Only the subtree is visited, but a copy of every removed key is allocated
+    /// to look it up for removal.
+    pub fn remove_subtree(&mut self, prefix: &AbsoluteOid) -> OidMap<V> {
+        let keys: Vec<AbsoluteOidVec> = self
+            .map
+            .range::<AbsoluteOid, _>((Bound::Included(prefix), Bound::Unbounded))
+            .map(|(key, _)| key)
+            .take_while(|key| key.starts_with(prefix))
+            .cloned()
+            .collect();
+        let map = keys
+            .into_iter()
+            .filter_map(|key| self.map.remove_entry(&key))
+            .collect();
+        OidMap { map }
+    }
+
+    /// The first entry after `oid` in arc order, as in SNMP GETNEXT
+    pub fn get_next<'a>(&'a self, oid: &AbsoluteOid) -> Option<(&'a AbsoluteOid, &'a V)> {
+        self.map
+            .range::<AbsoluteOid, _>((Bound::Excluded(oid), Bound::Unbounded))
+            .next()
+            .map(|(key, value)| (key.as_oid(), value))
+    }
+
+    /// All entries in arc order
+    pub fn iter(&self) -> OidMapIter<'_, V> {
+        OidMapIter {
+            iter: self.map.iter(),
+        }
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for OidMap<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a OidMap<V> {
+    type Item = (&'a AbsoluteOid, &'a V);
+    type IntoIter = OidMapIter<'a, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'k, V> Extend<(&'k AbsoluteOid, V)> for OidMap<V> {
+    fn extend<I: IntoIterator<Item = (&'k AbsoluteOid, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<V> Extend<(AbsoluteOidVec, V)> for OidMap<V> {
+    fn extend<I: IntoIterator<Item = (AbsoluteOidVec, V)>>(&mut self, iter: I) {
+        self.map.extend(iter)
+    }
+}
+
+impl<V> FromIterator<(AbsoluteOidVec, V)> for OidMap<V> {
+    fn from_iter<I: IntoIterator<Item = (AbsoluteOidVec, V)>>(iter: I) -> Self {
+        OidMap {
+            map: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Iterator over entries of [OidMap], see [OidMap::iter]
+pub struct OidMapIter<'a, V> {
+    iter: btree_map::Iter<'a, AbsoluteOidVec, V>,
+}
+
+impl<'a, V> Iterator for OidMapIter<'a, V> {
+    type Item = (&'a AbsoluteOid, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, value)| (key.as_oid(), value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Iterator over a subtree of [OidMap], see [OidMap::subtree]
+pub struct OidSubtree<'a, V> {
+    range: btree_map::Range<'a, AbsoluteOidVec, V>,
+    prefix: &'a AbsoluteOid,
+}
+
+impl<'a, V> Iterator for OidSubtree<'a, V> {
+    type Item = (&'a AbsoluteOid, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the subtree is contiguous, the first key outside of it ends the iteration
+        let (key, value) = self.range.next()?;
+        if key.starts_with(self.prefix) {
+            Some((key.as_oid(), value))
+        } else {
+            self.range = Default::default();
+            None
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Formatting of oids with arc names, e.g. `iso(1).member-body(2).us(840)`
+
+use core::fmt;
+
+use crate::{encode::b128_len, AbsoluteOid};
+
+/// Source of arc names for [AbsoluteOid::display_with]
+///
+/// Names of the first arc are fixed (`itu-t`, `iso`, `joint-iso-itu-t`),
+/// the table is asked about the rest.
+pub trait NameTable {
+    /// Name of the last arc of `oid`
+    fn name(&self, oid: &AbsoluteOid) -> Option<&str>;
+}
+
+impl<T: NameTable + ?Sized> NameTable for &T {
+    fn name(&self, oid: &AbsoluteOid) -> Option<&str> {
+        (**self).name(oid)
+    }
+}
+
+/// [AbsoluteOid] formatted with arc names, see [AbsoluteOid::display_with]
+pub struct DisplayWith<'a, T: ?Sized> {
+    oid: &'a AbsoluteOid,
+    names: &'a T,
+}
+
+impl AbsoluteOid {
+    /// Formats the oid with names from `names`, arcs without a name are printed as numbers
+    ///
+    /// `{}` prints dotted form, `{:#}` prints ASN.1 value notation.
+    /// ```
+    /// # use oid_str::{AbsoluteOid, AbsoluteOidVec, OidMap};
+    /// let mut names = OidMap::new();
+    /// names.insert(&"1.2".parse::<AbsoluteOidVec>().unwrap(), "member-body");
+    /// names.insert(&"1.2.840".parse::<AbsoluteOidVec>().unwrap(), "us");
+    ///
+    /// let oid: AbsoluteOidVec = "1.2.840.113549".parse().unwrap();
+    /// let named = oid.display_with(&names);
+    /// assert_eq!(named.to_string(), "iso(1).member-body(2).us(840).113549");
+    /// assert_eq!(format!("{:#}", named), "{ iso(1) member-body(2) us(840) 113549 }");
+    /// ```
+    ///
+    /// Plain `{:#}` on the oid itself prints dotted form with names from
+    /// [registry](crate::registry), if `registry` feature is enabled.
+    pub fn display_with<'a, T: NameTable + ?Sized>(&'a self, names: &'a T) -> DisplayWith<'a, T> {
+        DisplayWith { oid: self, names }
+    }
+}
+
+impl<T: NameTable + ?Sized> fmt::Display for DisplayWith<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("{ ")?;
+            write_named(self.oid, self.names, " ", f)?;
+            f.write_str(" }")
+        } else {
+            write_named(self.oid, self.names, ".", f)
+        }
+    }
+}
+
+/// Names used by `{:#}` of [AbsoluteOid]
+pub(crate) struct DefaultNames;
+
+impl NameTable for DefaultNames {
+    #[cfg(feature = "registry")]
+    fn name(&self, oid: &AbsoluteOid) -> Option<&str> {
+        crate::registry::name_of(oid)
+    }
+
+    #[cfg(not(feature = "registry"))]
+    fn name(&self, _oid: &AbsoluteOid) -> Option<&str> {
+        None
+    }
+}
+
+pub(crate) fn write_named<T: NameTable + ?Sized>(
+    oid: &AbsoluteOid,
+    names: &T,
+    separator: &str,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let (arc0, arc1) = oid.root().into_arcs();
+    write!(f, "{}({})", arc0.name(), arc0 as u8)?;
+
+    // every prefix of `oid` is a shorter tail, ending at an arc boundary
+    let tail = oid.tail();
+    f.write_str(separator)?;
+    write_arc(f, names.name(oid.with_tail(&tail[..0])), arc1.as_arc())?;
+    let mut end = 0;
+    for arc in tail.arcs() {
+        end += b128_len(arc);
+        f.write_str(separator)?;
+        write_arc(f, names.name(oid.with_tail(&tail[..end])), arc)?;
+    }
+    Ok(())
+}
+
+fn write_arc(f: &mut fmt::Formatter<'_>, name: Option<&str>, arc: crate::Arc) -> fmt::Result {
+    match name {
+        Some(name) => write!(f, "{}({})", name, arc),
+        None => write!(f, "{}", arc),
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod owned {
+    use crate::{AbsoluteOid, NameTable, OidMap};
+
+    impl<V: AsRef<str>> NameTable for OidMap<V> {
+        fn name(&self, oid: &AbsoluteOid) -> Option<&str> {
+            self.get(oid).map(AsRef::as_ref)
+        }
+    }
+}
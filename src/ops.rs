@@ -1,5 +1,3 @@
-use core::ops::RangeBounds;
-
 use crate::{index::slice_error_fail, AbsoluteOid, RelativeOid};
 
 impl RelativeOid {
@@ -82,12 +80,6 @@ impl RelativeOid {
             dst.copy_from_slice(src);
         }
     }
-
-    pub(crate) unsafe fn copy_within<R: RangeBounds<usize>>(&mut self, src: R, dest: usize) {
-        let dst = self.as_mut_bytes();
-
-        dst.copy_within(src, dest);
-    }
 }
 
 impl AbsoluteOid {
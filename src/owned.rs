@@ -1,8 +1,9 @@
 use alloc::vec::Vec;
+use core::ops::{Bound, Range, RangeBounds};
 
 use crate::{encode::write_b128, AbsoluteOid, Arc, RelativeOid, RootOid, ARC_LEN};
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct RelativeOidVec {
     bytes: Vec<u8>,
 }
@@ -57,9 +58,95 @@ impl RelativeOidVec {
     pub fn extend(&mut self, oid: &RelativeOid) {
         self.bytes.extend(oid.as_bytes())
     }
+
+    fn arcs_mut(&mut self) -> ArcsMut<'_> {
+        ArcsMut {
+            bytes: &mut self.bytes,
+            start: 0,
+            first: 0,
+        }
+    }
+
+    /// Removes the last arc
+    pub fn pop(&mut self) -> Option<Arc> {
+        self.arcs_mut().pop()
+    }
+
+    /// Keeps the first `n` arcs
+    pub fn truncate_arcs(&mut self, n: usize) {
+        self.arcs_mut().truncate(n)
+    }
+
+    /// Inserts `arc` at position `index`, shifting the following arcs
+    ///
+    /// # Panics
+    /// If `index` is greater than the number of arcs
+    pub fn insert_arc(&mut self, index: usize, arc: Arc) {
+        self.arcs_mut().insert(index, arc)
+    }
+
+    /// Removes the arc at position `index`
+    ///
+    /// # Panics
+    /// If there is no such arc
+    pub fn remove_arc(&mut self, index: usize) -> Arc {
+        self.arcs_mut().remove(index)
+    }
+
+    /// Replaces the arc at position `index`, returns the old one
+    ///
+    /// ```
+    /// # use oid_str::RelativeOidVec;
+    /// let mut oid: RelativeOidVec = ".1.2.3".parse().unwrap();
+    /// assert_eq!(oid.set_arc(1, 840), 2);
+    /// assert_eq!(oid.to_string(), ".1.840.3");
+    /// ```
+    ///
+    /// # Panics
+    /// If there is no such arc
+    pub fn set_arc(&mut self, index: usize, arc: Arc) -> Arc {
+        self.arcs_mut().set(index, arc)
+    }
+
+    /// Replaces arcs in `range` with arcs of `oid`
+    ///
+    /// # Panics
+    /// If the range is out of bounds
+    pub fn replace_range<R: RangeBounds<usize>>(&mut self, range: R, oid: &RelativeOid) {
+        self.arcs_mut().replace_range(range, oid.as_bytes())
+    }
+
+    /// Keeps only the arcs, for which `f` returns `true`
+    pub fn retain<F: FnMut(Arc) -> bool>(&mut self, f: F) {
+        self.arcs_mut().retain(f)
+    }
+
+    /// Removes all arcs
+    pub fn clear(&mut self) {
+        self.bytes.clear()
+    }
 }
 
-#[derive(Clone, PartialEq, Eq)]
+/// Owned [AbsoluteOid]
+///
+/// Arc level operations index arcs the same way as [AbsoluteOid::arcs],
+/// i.e. indices `0` and `1` are the root, and index `2` is the third arc.
+/// The root can't be edited this way: it is never removed,
+/// and the operations panic on indices that point into it.
+/// ```
+/// # use oid_str::AbsoluteOidVec;
+/// let mut oid: AbsoluteOidVec = "1.3.6.1.4.1".parse().unwrap();
+/// oid.remove_arc(2);
+/// oid.insert_arc(4, 311);
+/// assert_eq!(oid.to_string(), "1.3.1.4.311.1");
+/// oid.retain(|arc| arc != 1);
+/// assert_eq!(oid.to_string(), "1.3.4.311");
+/// assert_eq!(oid.pop(), Some(311));
+/// oid.clear();
+/// assert_eq!(oid.pop(), None);
+/// assert_eq!(oid.to_string(), "1.3");
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct AbsoluteOidVec {
     bytes: Vec<u8>,
 }
@@ -112,4 +199,210 @@ impl AbsoluteOidVec {
     pub fn extend(&mut self, oid: &RelativeOid) {
         self.bytes.extend(oid.as_bytes())
     }
+
+    fn arcs_mut(&mut self) -> ArcsMut<'_> {
+        let start = self.root_len();
+        ArcsMut {
+            bytes: &mut self.bytes,
+            start,
+            first: 2,
+        }
+    }
+
+    /// Removes the last arc, the root is never removed
+    pub fn pop(&mut self) -> Option<Arc> {
+        self.arcs_mut().pop()
+    }
+
+    /// Keeps the first `n` arcs, the root is always kept
+    pub fn truncate_arcs(&mut self, n: usize) {
+        self.arcs_mut().truncate(n)
+    }
+
+    /// Inserts `arc` at position `index`, shifting the following arcs
+    ///
+    /// # Panics
+    /// If `index` is less than `2` or greater than the number of arcs
+    pub fn insert_arc(&mut self, index: usize, arc: Arc) {
+        self.arcs_mut().insert(index, arc)
+    }
+
+    /// Removes the arc at position `index`
+    ///
+    /// # Panics
+    /// If `index` is less than `2` or there is no such arc
+    pub fn remove_arc(&mut self, index: usize) -> Arc {
+        self.arcs_mut().remove(index)
+    }
+
+    /// Replaces the arc at position `index`, returns the old one
+    ///
+    /// # Panics
+    /// If `index` is less than `2` or there is no such arc
+    pub fn set_arc(&mut self, index: usize, arc: Arc) -> Arc {
+        self.arcs_mut().set(index, arc)
+    }
+
+    /// Replaces arcs in `range` with arcs of `oid`
+    ///
+    /// # Panics
+    /// If the range includes the root or is out of bounds
+    pub fn replace_range<R: RangeBounds<usize>>(&mut self, range: R, oid: &RelativeOid) {
+        self.arcs_mut().replace_range(range, oid.as_bytes())
+    }
+
+    /// Keeps the root and the arcs, for which `f` returns `true`
+    pub fn retain<F: FnMut(Arc) -> bool>(&mut self, f: F) {
+        self.arcs_mut().retain(f)
+    }
+
+    /// Removes all arcs after the root
+    pub fn clear(&mut self) {
+        self.arcs_mut().truncate(0)
+    }
+}
+
+/// Arc level editing of encoded arcs in `bytes[start..]`
+///
+/// Arcs before `start` are counted, but can't be edited,
+/// so the arc at `start` has index `first`.
+struct ArcsMut<'a> {
+    bytes: &'a mut Vec<u8>,
+    start: usize,
+    first: usize,
+}
+
+impl ArcsMut<'_> {
+    /// Index of arc `index` among the arcs after `start`
+    fn editable(&self, index: usize) -> usize {
+        match index.checked_sub(self.first) {
+            Some(index) => index,
+            None => panic!("arc index (is {}) should be >= {}, the root can't be edited", index, self.first),
+        }
+    }
+
+    /// Byte position of arc `index` after `start`, `index` may be the number of arcs
+    fn position(&self, index: usize) -> Option<usize> {
+        if index == 0 {
+            return Some(self.start);
+        }
+        let mut arcs = 0;
+        for (pos, &byte) in self.bytes.iter().enumerate().skip(self.start) {
+            if byte & 0x80 == 0 {
+                arcs += 1;
+                if arcs == index {
+                    return Some(pos + 1);
+                }
+            }
+        }
+        None
+    }
+
+    /// Number of arcs, including the ones before `start`
+    fn count(&self) -> usize {
+        self.first + self.bytes[self.start..].iter().filter(|&&byte| byte & 0x80 == 0).count()
+    }
+
+    /// Byte range of arc `index` after `start`
+    fn arc_range(&self, index: usize) -> Option<Range<usize>> {
+        let start = self.position(index)?;
+        let len = self.bytes[start..].iter().position(|&byte| byte & 0x80 == 0)? + 1;
+        Some(start..start + len)
+    }
+
+    /// Arc at `range`, as returned by [ArcsMut::arc_range]
+    fn arc(&self, range: Range<usize>) -> Arc {
+        // SAFETY: range covers exactly one encoded arc
+        let oid = unsafe { RelativeOid::from_bytes_unchecked(&self.bytes[range]) };
+        oid.arcs().next().unwrap()
+    }
+
+    fn arc_or_panic(&self, index: usize) -> Range<usize> {
+        match self.arc_range(self.editable(index)) {
+            Some(range) => range,
+            None => panic!("arc index (is {}) should be < number of arcs (is {})", index, self.count()),
+        }
+    }
+
+    fn pop(&mut self) -> Option<Arc> {
+        let end = self.bytes.len();
+        if end == self.start {
+            return None;
+        }
+        // the last byte finishes the last arc, which starts after the previous finishing byte
+        let start = self.bytes[self.start..end - 1]
+            .iter()
+            .rposition(|&byte| byte & 0x80 == 0)
+            .map_or(self.start, |pos| self.start + pos + 1);
+        let arc = self.arc(start..end);
+        self.bytes.truncate(start);
+        Some(arc)
+    }
+
+    fn truncate(&mut self, n: usize) {
+        if let Some(pos) = self.position(n.saturating_sub(self.first)) {
+            self.bytes.truncate(pos);
+        }
+    }
+
+    fn insert(&mut self, index: usize, arc: Arc) {
+        let pos = match self.position(self.editable(index)) {
+            Some(pos) => pos,
+            None => panic!("insertion index (is {}) should be <= number of arcs (is {})", index, self.count()),
+        };
+        let mut buffer = [0u8; ARC_LEN];
+        let arc = write_b128(&mut buffer, arc);
+        self.bytes.splice(pos..pos, arc.as_bytes().iter().copied());
+    }
+
+    fn remove(&mut self, index: usize) -> Arc {
+        let range = self.arc_or_panic(index);
+        let arc = self.arc(range.clone());
+        self.bytes.drain(range);
+        arc
+    }
+
+    fn set(&mut self, index: usize, arc: Arc) -> Arc {
+        let range = self.arc_or_panic(index);
+        let old = self.arc(range.clone());
+        let mut buffer = [0u8; ARC_LEN];
+        let arc = write_b128(&mut buffer, arc);
+        self.bytes.splice(range, arc.as_bytes().iter().copied());
+        old
+    }
+
+    fn replace_range<R: RangeBounds<usize>>(&mut self, range: R, arcs: &[u8]) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.count(),
+        };
+        let from = self.position(self.editable(start));
+        let to = end.checked_sub(self.first).and_then(|end| self.position(end));
+        let (from, to) = match (from, to) {
+            (Some(from), Some(to)) if start <= end => (from, to),
+            _ => panic!("arc range {}..{} is out of bounds of {} arcs", start, end, self.count()),
+        };
+        self.bytes.splice(from..to, arcs.iter().copied());
+    }
+
+    fn retain<F: FnMut(Arc) -> bool>(&mut self, mut f: F) {
+        let mut read = self.start;
+        let mut write = self.start;
+        while read < self.bytes.len() {
+            let len = self.bytes[read..].iter().position(|&byte| byte & 0x80 == 0).unwrap() + 1;
+            let range = read..read + len;
+            if f(self.arc(range.clone())) {
+                self.bytes.copy_within(range, write);
+                write += len;
+            }
+            read += len;
+        }
+        self.bytes.truncate(write);
+    }
 }
@@ -0,0 +1,208 @@
+//! Perfect hash tables, built during const evaluation
+//!
+//! Hash-and-displace scheme: keys are split into buckets by hash,
+//! largest buckets are placed first, each one with its own displacement.
+//! Lookup is a single hash computation and two array reads.
+
+const EMPTY: u16 = u16::MAX;
+/// Number of seeds to try, before giving up
+const MAX_SEEDS: u64 = 32;
+
+/// Perfect hash of `N` keys into `N` slots, with `B` buckets
+pub(crate) struct Phf<const B: usize, const N: usize> {
+    seed: u64,
+    displacements: [(u32, u32); B],
+    /// Index of the key in each slot
+    slots: [u16; N],
+}
+
+impl<const B: usize, const N: usize> Phf<B, N> {
+    /// Builds a table for `keys`, panics if keys are not unique
+    pub(crate) const fn build(keys: &[&[u8]; N]) -> Phf<B, N> {
+        if N >= EMPTY as usize {
+            panic!("too many keys");
+        }
+        check_unique(keys);
+        let mut seed = 0;
+        while seed < MAX_SEEDS {
+            if let Some(phf) = try_build(keys, seed) {
+                return phf;
+            }
+            seed += 1;
+        }
+        panic!("failed to build a perfect hash");
+    }
+
+    /// Index of the only key, that might be equal to `key`
+    pub(crate) fn index(&self, key: &[u8]) -> usize {
+        let hashes = Hashes::new(self.seed, key);
+        let (d1, d2) = self.displacements[hashes.bucket(B)];
+        self.slots[hashes.slot(d1, d2, N)] as usize
+    }
+}
+
+const fn check_unique(keys: &[&[u8]]) {
+    let mut i = 0;
+    while i < keys.len() {
+        let mut j = i + 1;
+        while j < keys.len() {
+            if bytes_eq(keys[i], keys[j]) {
+                panic!("duplicate key");
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn try_build<const B: usize, const N: usize>(keys: &[&[u8]; N], seed: u64) -> Option<Phf<B, N>> {
+    let mut hashes = [Hashes { bucket: 0, f1: 0, f2: 0 }; N];
+    let mut sizes = [0usize; B];
+    let mut i = 0;
+    while i < N {
+        hashes[i] = Hashes::new(seed, keys[i]);
+        sizes[hashes[i].bucket(B)] += 1;
+        i += 1;
+    }
+
+    // keys sorted by bucket, `starts[b]` is the position of the first key of bucket `b`
+    let mut starts = [0usize; B];
+    let mut max_size = 0;
+    let mut b = 1;
+    while b < B {
+        starts[b] = starts[b - 1] + sizes[b - 1];
+        b += 1;
+    }
+    let mut order = [0usize; N];
+    let mut filled = [0usize; B];
+    i = 0;
+    while i < N {
+        let b = hashes[i].bucket(B);
+        order[starts[b] + filled[b]] = i;
+        filled[b] += 1;
+        if filled[b] > max_size {
+            max_size = filled[b];
+        }
+        i += 1;
+    }
+
+    let mut displacements = [(0, 0); B];
+    let mut slots = [EMPTY; N];
+    let mut size = max_size;
+    while size > 0 {
+        let mut b = 0;
+        while b < B {
+            if sizes[b] == size {
+                let members = (starts[b], size);
+                match place(&hashes, &order, members, slots) {
+                    Some((d, placed)) => {
+                        displacements[b] = d;
+                        slots = placed;
+                    }
+                    None => return None,
+                }
+            }
+            b += 1;
+        }
+        size -= 1;
+    }
+    Some(Phf { seed, displacements, slots })
+}
+
+/// Finds a displacement, that puts all keys of the bucket into free slots, and fills those slots
+const fn place<const N: usize>(
+    hashes: &[Hashes; N],
+    order: &[usize; N],
+    (start, size): (usize, usize),
+    mut slots: [u16; N],
+) -> Option<((u32, u32), [u16; N])> {
+    let mut d1 = 0;
+    while d1 < N as u32 {
+        let mut d2 = 0;
+        'displacement: while d2 < N as u32 {
+            let mut j = 0;
+            while j < size {
+                let slot = hashes[order[start + j]].slot(d1, d2, N);
+                if slots[slot] != EMPTY {
+                    d2 += 1;
+                    continue 'displacement;
+                }
+                let mut k = 0;
+                while k < j {
+                    if hashes[order[start + k]].slot(d1, d2, N) == slot {
+                        d2 += 1;
+                        continue 'displacement;
+                    }
+                    k += 1;
+                }
+                j += 1;
+            }
+            j = 0;
+            while j < size {
+                let key = order[start + j];
+                slots[hashes[key].slot(d1, d2, N)] = key as u16;
+                j += 1;
+            }
+            return Some(((d1, d2), slots));
+        }
+        d1 += 1;
+    }
+    None
+}
+
+#[derive(Clone, Copy)]
+struct Hashes {
+    bucket: u32,
+    f1: u32,
+    f2: u32,
+}
+
+impl Hashes {
+    /// FNV-1a, followed by a mixing step, so that short keys spread over all bits
+    const fn new(seed: u64, key: &[u8]) -> Hashes {
+        let mut hash = 0xcbf2_9ce4_8422_2325 ^ seed;
+        let mut i = 0;
+        while i < key.len() {
+            hash ^= key[i] as u64;
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+            i += 1;
+        }
+        let hash = mix(hash);
+        let extra = mix(hash);
+        Hashes {
+            bucket: (hash >> 32) as u32,
+            f1: hash as u32,
+            f2: extra as u32,
+        }
+    }
+
+    const fn bucket(&self, buckets: usize) -> usize {
+        self.bucket as usize % buckets
+    }
+
+    const fn slot(&self, d1: u32, d2: u32, slots: usize) -> usize {
+        let slot = self.f1.wrapping_mul(d1).wrapping_add(self.f2).wrapping_add(d2);
+        slot as usize % slots
+    }
+}
+
+/// Finalizer of splitmix64
+const fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
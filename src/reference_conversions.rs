@@ -1,47 +1,84 @@
-use core::borrow::{Borrow, BorrowMut};
+use crate::{AbsoluteOid, RelativeOid};
 
-use crate::{AbsoluteOid, AbsoluteOidVec, RelativeOid, RelativeOidVec};
+#[cfg(feature = "alloc")]
+mod with_alloc {
+    use super::*;
+    use alloc::borrow::ToOwned;
+    use core::borrow::{Borrow, BorrowMut};
+
+    use crate::{AbsoluteOidVec, RelativeOidVec, SmallAbsoluteOid, SmallRelativeOid};
 
-impl AsRef<AbsoluteOid> for AbsoluteOidVec {
-    fn as_ref(&self) -> &AbsoluteOid {
-        self.as_oid()
+    impl AsRef<AbsoluteOid> for AbsoluteOidVec {
+        fn as_ref(&self) -> &AbsoluteOid {
+            self.as_oid()
+        }
     }
-}
 
-impl Borrow<AbsoluteOid> for AbsoluteOidVec {
-    fn borrow(&self) -> &AbsoluteOid {
-        self.as_oid()
+    impl Borrow<AbsoluteOid> for AbsoluteOidVec {
+        fn borrow(&self) -> &AbsoluteOid {
+            self.as_oid()
+        }
     }
-}
 
-impl BorrowMut<AbsoluteOid> for AbsoluteOidVec {
-    fn borrow_mut(&mut self) -> &mut AbsoluteOid {
-        self.as_mut_oid()
+    impl BorrowMut<AbsoluteOid> for AbsoluteOidVec {
+        fn borrow_mut(&mut self) -> &mut AbsoluteOid {
+            self.as_mut_oid()
+        }
     }
-}
 
-impl AsRef<RelativeOid> for RelativeOidVec {
-    fn as_ref(&self) -> &RelativeOid {
-        self.as_oid()
+    impl AsRef<RelativeOid> for RelativeOidVec {
+        fn as_ref(&self) -> &RelativeOid {
+            self.as_oid()
+        }
     }
-}
 
-impl Borrow<RelativeOid> for RelativeOidVec {
-    fn borrow(&self) -> &RelativeOid {
-        self.as_oid()
+    impl Borrow<RelativeOid> for RelativeOidVec {
+        fn borrow(&self) -> &RelativeOid {
+            self.as_oid()
+        }
     }
-}
 
-impl BorrowMut<RelativeOid> for RelativeOidVec {
-    fn borrow_mut(&mut self) -> &mut RelativeOid {
-        self.as_mut_oid()
+    impl BorrowMut<RelativeOid> for RelativeOidVec {
+        fn borrow_mut(&mut self) -> &mut RelativeOid {
+            self.as_mut_oid()
+        }
     }
-}
 
-#[cfg(feature = "alloc")]
-mod with_alloc {
-    use super::*;
-    use alloc::borrow::ToOwned;
+    impl AsRef<AbsoluteOid> for SmallAbsoluteOid {
+        fn as_ref(&self) -> &AbsoluteOid {
+            self.as_oid()
+        }
+    }
+
+    impl Borrow<AbsoluteOid> for SmallAbsoluteOid {
+        fn borrow(&self) -> &AbsoluteOid {
+            self.as_oid()
+        }
+    }
+
+    impl BorrowMut<AbsoluteOid> for SmallAbsoluteOid {
+        fn borrow_mut(&mut self) -> &mut AbsoluteOid {
+            self.as_mut_oid()
+        }
+    }
+
+    impl AsRef<RelativeOid> for SmallRelativeOid {
+        fn as_ref(&self) -> &RelativeOid {
+            self.as_oid()
+        }
+    }
+
+    impl Borrow<RelativeOid> for SmallRelativeOid {
+        fn borrow(&self) -> &RelativeOid {
+            self.as_oid()
+        }
+    }
+
+    impl BorrowMut<RelativeOid> for SmallRelativeOid {
+        fn borrow_mut(&mut self) -> &mut RelativeOid {
+            self.as_mut_oid()
+        }
+    }
 
     impl ToOwned for AbsoluteOid {
         type Owned = AbsoluteOidVec;
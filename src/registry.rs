@@ -0,0 +1,324 @@
+//! Well-known oids and their ASN.1 names, enabled with `registry` feature
+//!
+//! Names are the identifiers used in ASN.1 modules of the defining standards.
+//! Lookups in both directions use perfect hash tables, built at compile time.
+//! ```
+//! # use oid_str::{registry, AbsoluteOid};
+//! let oid = AbsoluteOid::from_bytes(b"\x2a\x86\x48\x86\xf7\x0d\x01\x01\x0b").unwrap();
+//! assert_eq!(registry::name_of(oid), Some("sha256WithRSAEncryption"));
+//!
+//! let san = registry::by_name("id-ce-subjectAltName").unwrap();
+//! assert_eq!(san, &*registry::ID_CE_SUBJECT_ALT_NAME);
+//! assert_eq!(san.to_string(), "2.5.29.17");
+//! ```
+
+use crate::{oid, phf::Phf, AbsoluteOid, NameTable, OidResolver, StaticAbsoluteOid};
+
+/// [NameTable] and [OidResolver] backed by the registry
+/// ```
+/// # use oid_str::{registry::{self, Registry}};
+/// let oid = registry::SHA256_WITH_RSA_ENCRYPTION;
+/// assert_eq!(
+///     format!("{:#}", oid.display_with(&Registry)),
+///     "{ iso(1) member-body(2) us(840) rsadsi(113549) pkcs(1) pkcs-1(1) sha256WithRSAEncryption(11) }",
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Registry;
+
+impl NameTable for Registry {
+    fn name(&self, oid: &AbsoluteOid) -> Option<&str> {
+        name_of(oid)
+    }
+}
+
+impl OidResolver for Registry {
+    fn resolve(&self, name: &str) -> Option<&AbsoluteOid> {
+        by_name(name)
+    }
+}
+
+/// Name of `oid`, if it is in the registry
+pub fn name_of(oid: &AbsoluteOid) -> Option<&'static str> {
+    let (name, entry) = &ENTRIES[BY_OID.index(oid.as_bytes())];
+    if entry.as_bytes() == oid.as_bytes() {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+/// Oid named `name`, if it is in the registry
+pub fn by_name(name: &str) -> Option<&'static AbsoluteOid> {
+    let (entry_name, oid) = &ENTRIES[BY_NAME.index(name.as_bytes())];
+    if *entry_name == name {
+        Some(&**oid)
+    } else {
+        None
+    }
+}
+
+/// All registered oids with their names, in the order of definition
+pub fn entries() -> impl Iterator<Item = (&'static str, &'static AbsoluteOid)> {
+    ENTRIES.iter().map(|(name, oid)| (*name, &**oid))
+}
+
+const N: usize = ENTRIES.len();
+const B: usize = N.div_ceil(4);
+
+const BY_NAME: Phf<B, N> = {
+    let mut keys: [&[u8]; N] = [&[]; N];
+    let mut i = 0;
+    while i < N {
+        keys[i] = ENTRIES[i].0.as_bytes();
+        i += 1;
+    }
+    Phf::build(&keys)
+};
+
+const BY_OID: Phf<B, N> = {
+    let mut keys: [&[u8]; N] = [&[]; N];
+    let mut i = 0;
+    while i < N {
+        keys[i] = ENTRIES[i].1.bytes();
+        i += 1;
+    }
+    Phf::build(&keys)
+};
+
+/// Defines a constant for each oid, and the table of all of them
+macro_rules! registry {
+    ($($ident:ident = $name:literal, $oid:literal;)*) => {
+        $(
+            #[doc = concat!("`", $name, "`, ", $oid)]
+            pub const $ident: StaticAbsoluteOid<'static> = oid!($oid);
+        )*
+
+        const ENTRIES: &[(&str, StaticAbsoluteOid<'static>)] = &[$(($name, $ident)),*];
+    };
+}
+
+registry! {
+    // Top-level arcs
+    STANDARD = "standard", "1.0";
+    MEMBER_BODY = "member-body", "1.2";
+    US = "us", "1.2.840";
+    RSADSI = "rsadsi", "1.2.840.113549";
+    PKCS = "pkcs", "1.2.840.113549.1";
+    ANSI_X9_62 = "ansi-X9-62", "1.2.840.10045";
+    IDENTIFIED_ORGANIZATION = "identified-organization", "1.3";
+    DOD = "dod", "1.3.6";
+    CERTICOM_ARC = "certicom-arc", "1.3.132";
+    ELLIPTIC_CURVE = "ellipticCurve", "1.3.132.0";
+    DS = "ds", "2.5";
+    ID_AT = "id-at", "2.5.4";
+    ID_CE = "id-ce", "2.5.29";
+    COUNTRY = "country", "2.16";
+    NIST_ALGORITHMS = "nistAlgorithms", "2.16.840.1.101.3.4";
+    AES = "aes", "2.16.840.1.101.3.4.1";
+    HASH_ALGS = "hashAlgs", "2.16.840.1.101.3.4.2";
+    SIG_ALGS = "sigAlgs", "2.16.840.1.101.3.4.3";
+    KEMS = "kems", "2.16.840.1.101.3.4.4";
+
+    // X.500 attribute types
+    ID_AT_COMMON_NAME = "id-at-commonName", "2.5.4.3";
+    ID_AT_SURNAME = "id-at-surname", "2.5.4.4";
+    ID_AT_SERIAL_NUMBER = "id-at-serialNumber", "2.5.4.5";
+    ID_AT_COUNTRY_NAME = "id-at-countryName", "2.5.4.6";
+    ID_AT_LOCALITY_NAME = "id-at-localityName", "2.5.4.7";
+    ID_AT_STATE_OR_PROVINCE_NAME = "id-at-stateOrProvinceName", "2.5.4.8";
+    ID_AT_STREET_ADDRESS = "id-at-streetAddress", "2.5.4.9";
+    ID_AT_ORGANIZATION_NAME = "id-at-organizationName", "2.5.4.10";
+    ID_AT_ORGANIZATIONAL_UNIT_NAME = "id-at-organizationalUnitName", "2.5.4.11";
+    ID_AT_TITLE = "id-at-title", "2.5.4.12";
+    ID_AT_POSTAL_CODE = "id-at-postalCode", "2.5.4.17";
+    ID_AT_NAME = "id-at-name", "2.5.4.41";
+    ID_AT_GIVEN_NAME = "id-at-givenName", "2.5.4.42";
+    ID_AT_INITIALS = "id-at-initials", "2.5.4.43";
+    ID_AT_GENERATION_QUALIFIER = "id-at-generationQualifier", "2.5.4.44";
+    ID_AT_DN_QUALIFIER = "id-at-dnQualifier", "2.5.4.46";
+    ID_AT_PSEUDONYM = "id-at-pseudonym", "2.5.4.65";
+    ID_DOMAIN_COMPONENT = "id-domainComponent", "0.9.2342.19200300.100.1.25";
+
+    // X.509 certificate and CRL extensions
+    ID_CE_SUBJECT_DIRECTORY_ATTRIBUTES = "id-ce-subjectDirectoryAttributes", "2.5.29.9";
+    ID_CE_SUBJECT_KEY_IDENTIFIER = "id-ce-subjectKeyIdentifier", "2.5.29.14";
+    ID_CE_KEY_USAGE = "id-ce-keyUsage", "2.5.29.15";
+    ID_CE_PRIVATE_KEY_USAGE_PERIOD = "id-ce-privateKeyUsagePeriod", "2.5.29.16";
+    ID_CE_SUBJECT_ALT_NAME = "id-ce-subjectAltName", "2.5.29.17";
+    ID_CE_ISSUER_ALT_NAME = "id-ce-issuerAltName", "2.5.29.18";
+    ID_CE_BASIC_CONSTRAINTS = "id-ce-basicConstraints", "2.5.29.19";
+    ID_CE_CRL_NUMBER = "id-ce-cRLNumber", "2.5.29.20";
+    ID_CE_CRL_REASONS = "id-ce-cRLReasons", "2.5.29.21";
+    ID_CE_HOLD_INSTRUCTION_CODE = "id-ce-holdInstructionCode", "2.5.29.23";
+    ID_CE_INVALIDITY_DATE = "id-ce-invalidityDate", "2.5.29.24";
+    ID_CE_DELTA_CRL_INDICATOR = "id-ce-deltaCRLIndicator", "2.5.29.27";
+    ID_CE_ISSUING_DISTRIBUTION_POINT = "id-ce-issuingDistributionPoint", "2.5.29.28";
+    ID_CE_CERTIFICATE_ISSUER = "id-ce-certificateIssuer", "2.5.29.29";
+    ID_CE_NAME_CONSTRAINTS = "id-ce-nameConstraints", "2.5.29.30";
+    ID_CE_CRL_DISTRIBUTION_POINTS = "id-ce-cRLDistributionPoints", "2.5.29.31";
+    ID_CE_CERTIFICATE_POLICIES = "id-ce-certificatePolicies", "2.5.29.32";
+    ANY_POLICY = "anyPolicy", "2.5.29.32.0";
+    ID_CE_POLICY_MAPPINGS = "id-ce-policyMappings", "2.5.29.33";
+    ID_CE_AUTHORITY_KEY_IDENTIFIER = "id-ce-authorityKeyIdentifier", "2.5.29.35";
+    ID_CE_POLICY_CONSTRAINTS = "id-ce-policyConstraints", "2.5.29.36";
+    ID_CE_EXT_KEY_USAGE = "id-ce-extKeyUsage", "2.5.29.37";
+    ANY_EXTENDED_KEY_USAGE = "anyExtendedKeyUsage", "2.5.29.37.0";
+    ID_CE_FRESHEST_CRL = "id-ce-freshestCRL", "2.5.29.46";
+    ID_CE_INHIBIT_ANY_POLICY = "id-ce-inhibitAnyPolicy", "2.5.29.54";
+
+    // PKIX
+    ID_PKIX = "id-pkix", "1.3.6.1.5.5.7";
+    ID_PE = "id-pe", "1.3.6.1.5.5.7.1";
+    ID_PE_AUTHORITY_INFO_ACCESS = "id-pe-authorityInfoAccess", "1.3.6.1.5.5.7.1.1";
+    ID_PE_SUBJECT_INFO_ACCESS = "id-pe-subjectInfoAccess", "1.3.6.1.5.5.7.1.11";
+    ID_PE_TLSFEATURE = "id-pe-tlsfeature", "1.3.6.1.5.5.7.1.24";
+    ID_QT = "id-qt", "1.3.6.1.5.5.7.2";
+    ID_QT_CPS = "id-qt-cps", "1.3.6.1.5.5.7.2.1";
+    ID_QT_UNOTICE = "id-qt-unotice", "1.3.6.1.5.5.7.2.2";
+    ID_KP = "id-kp", "1.3.6.1.5.5.7.3";
+    ID_KP_SERVER_AUTH = "id-kp-serverAuth", "1.3.6.1.5.5.7.3.1";
+    ID_KP_CLIENT_AUTH = "id-kp-clientAuth", "1.3.6.1.5.5.7.3.2";
+    ID_KP_CODE_SIGNING = "id-kp-codeSigning", "1.3.6.1.5.5.7.3.3";
+    ID_KP_EMAIL_PROTECTION = "id-kp-emailProtection", "1.3.6.1.5.5.7.3.4";
+    ID_KP_TIME_STAMPING = "id-kp-timeStamping", "1.3.6.1.5.5.7.3.8";
+    ID_KP_OCSP_SIGNING = "id-kp-OCSPSigning", "1.3.6.1.5.5.7.3.9";
+    ID_AD = "id-ad", "1.3.6.1.5.5.7.48";
+    ID_AD_OCSP = "id-ad-ocsp", "1.3.6.1.5.5.7.48.1";
+    ID_PKIX_OCSP_BASIC = "id-pkix-ocsp-basic", "1.3.6.1.5.5.7.48.1.1";
+    ID_PKIX_OCSP_NONCE = "id-pkix-ocsp-nonce", "1.3.6.1.5.5.7.48.1.2";
+    ID_AD_CA_ISSUERS = "id-ad-caIssuers", "1.3.6.1.5.5.7.48.2";
+    ID_AD_TIME_STAMPING = "id-ad-timeStamping", "1.3.6.1.5.5.7.48.3";
+    ID_AD_CA_REPOSITORY = "id-ad-caRepository", "1.3.6.1.5.5.7.48.5";
+
+    // PKCS #1
+    PKCS_1 = "pkcs-1", "1.2.840.113549.1.1";
+    RSA_ENCRYPTION = "rsaEncryption", "1.2.840.113549.1.1.1";
+    MD5_WITH_RSA_ENCRYPTION = "md5WithRSAEncryption", "1.2.840.113549.1.1.4";
+    SHA1_WITH_RSA_ENCRYPTION = "sha1WithRSAEncryption", "1.2.840.113549.1.1.5";
+    ID_RSAES_OAEP = "id-RSAES-OAEP", "1.2.840.113549.1.1.7";
+    ID_MGF1 = "id-mgf1", "1.2.840.113549.1.1.8";
+    ID_P_SPECIFIED = "id-pSpecified", "1.2.840.113549.1.1.9";
+    ID_RSASSA_PSS = "id-RSASSA-PSS", "1.2.840.113549.1.1.10";
+    SHA256_WITH_RSA_ENCRYPTION = "sha256WithRSAEncryption", "1.2.840.113549.1.1.11";
+    SHA384_WITH_RSA_ENCRYPTION = "sha384WithRSAEncryption", "1.2.840.113549.1.1.12";
+    SHA512_WITH_RSA_ENCRYPTION = "sha512WithRSAEncryption", "1.2.840.113549.1.1.13";
+    SHA224_WITH_RSA_ENCRYPTION = "sha224WithRSAEncryption", "1.2.840.113549.1.1.14";
+
+    // PKCS #7
+    PKCS_7 = "pkcs-7", "1.2.840.113549.1.7";
+    ID_DATA = "id-data", "1.2.840.113549.1.7.1";
+    ID_SIGNED_DATA = "id-signedData", "1.2.840.113549.1.7.2";
+    ID_ENVELOPED_DATA = "id-envelopedData", "1.2.840.113549.1.7.3";
+    ID_DIGESTED_DATA = "id-digestedData", "1.2.840.113549.1.7.5";
+    ID_ENCRYPTED_DATA = "id-encryptedData", "1.2.840.113549.1.7.6";
+
+    // PKCS #9
+    PKCS_9 = "pkcs-9", "1.2.840.113549.1.9";
+    PKCS_9_AT_EMAIL_ADDRESS = "pkcs-9-at-emailAddress", "1.2.840.113549.1.9.1";
+    PKCS_9_AT_UNSTRUCTURED_NAME = "pkcs-9-at-unstructuredName", "1.2.840.113549.1.9.2";
+    PKCS_9_AT_CONTENT_TYPE = "pkcs-9-at-contentType", "1.2.840.113549.1.9.3";
+    PKCS_9_AT_MESSAGE_DIGEST = "pkcs-9-at-messageDigest", "1.2.840.113549.1.9.4";
+    PKCS_9_AT_SIGNING_TIME = "pkcs-9-at-signingTime", "1.2.840.113549.1.9.5";
+    PKCS_9_AT_COUNTER_SIGNATURE = "pkcs-9-at-counterSignature", "1.2.840.113549.1.9.6";
+    PKCS_9_AT_CHALLENGE_PASSWORD = "pkcs-9-at-challengePassword", "1.2.840.113549.1.9.7";
+    PKCS_9_AT_UNSTRUCTURED_ADDRESS = "pkcs-9-at-unstructuredAddress", "1.2.840.113549.1.9.8";
+    PKCS_9_AT_EXTENSION_REQUEST = "pkcs-9-at-extensionRequest", "1.2.840.113549.1.9.14";
+    PKCS_9_AT_SMIME_CAPABILITIES = "pkcs-9-at-smimeCapabilities", "1.2.840.113549.1.9.15";
+    PKCS_9_AT_FRIENDLY_NAME = "pkcs-9-at-friendlyName", "1.2.840.113549.1.9.20";
+    PKCS_9_AT_LOCAL_KEY_ID = "pkcs-9-at-localKeyId", "1.2.840.113549.1.9.21";
+
+    // PKCS #12
+    PKCS_12 = "pkcs-12", "1.2.840.113549.1.12";
+    PKCS_12_PBE_IDS = "pkcs-12PbeIds", "1.2.840.113549.1.12.1";
+    PBE_WITH_SHA_AND_128_BIT_RC4 = "pbeWithSHAAnd128BitRC4", "1.2.840.113549.1.12.1.1";
+    PBE_WITH_SHA_AND_3_KEY_TRIPLE_DES_CBC = "pbeWithSHAAnd3-KeyTripleDES-CBC", "1.2.840.113549.1.12.1.3";
+    PBE_WITH_SHA_AND_40_BIT_RC2_CBC = "pbeWithSHAAnd40BitRC2-CBC", "1.2.840.113549.1.12.1.6";
+    KEY_BAG = "keyBag", "1.2.840.113549.1.12.10.1.1";
+    PKCS8_SHROUDED_KEY_BAG = "pkcs8ShroudedKeyBag", "1.2.840.113549.1.12.10.1.2";
+    CERT_BAG = "certBag", "1.2.840.113549.1.12.10.1.3";
+    CRL_BAG = "crlBag", "1.2.840.113549.1.12.10.1.4";
+    SECRET_BAG = "secretBag", "1.2.840.113549.1.12.10.1.5";
+    SAFE_CONTENTS_BAG = "safeContentsBag", "1.2.840.113549.1.12.10.1.6";
+
+    // NIST algorithms
+    ID_SHA1 = "id-sha1", "1.3.14.3.2.26";
+    ID_AES128_WRAP = "id-aes128-wrap", "2.16.840.1.101.3.4.1.5";
+    ID_AES128_CBC = "id-aes128-CBC", "2.16.840.1.101.3.4.1.2";
+    ID_AES128_GCM = "id-aes128-GCM", "2.16.840.1.101.3.4.1.6";
+    ID_AES192_CBC = "id-aes192-CBC", "2.16.840.1.101.3.4.1.22";
+    ID_AES192_GCM = "id-aes192-GCM", "2.16.840.1.101.3.4.1.26";
+    ID_AES256_WRAP = "id-aes256-wrap", "2.16.840.1.101.3.4.1.45";
+    ID_AES256_CBC = "id-aes256-CBC", "2.16.840.1.101.3.4.1.42";
+    ID_AES256_GCM = "id-aes256-GCM", "2.16.840.1.101.3.4.1.46";
+    ID_SHA256 = "id-sha256", "2.16.840.1.101.3.4.2.1";
+    ID_SHA384 = "id-sha384", "2.16.840.1.101.3.4.2.2";
+    ID_SHA512 = "id-sha512", "2.16.840.1.101.3.4.2.3";
+    ID_SHA224 = "id-sha224", "2.16.840.1.101.3.4.2.4";
+    ID_SHA512_224 = "id-sha512-224", "2.16.840.1.101.3.4.2.5";
+    ID_SHA512_256 = "id-sha512-256", "2.16.840.1.101.3.4.2.6";
+    ID_SHA3_224 = "id-sha3-224", "2.16.840.1.101.3.4.2.7";
+    ID_SHA3_256 = "id-sha3-256", "2.16.840.1.101.3.4.2.8";
+    ID_SHA3_384 = "id-sha3-384", "2.16.840.1.101.3.4.2.9";
+    ID_SHA3_512 = "id-sha3-512", "2.16.840.1.101.3.4.2.10";
+    ID_SHAKE128 = "id-shake128", "2.16.840.1.101.3.4.2.11";
+    ID_SHAKE256 = "id-shake256", "2.16.840.1.101.3.4.2.12";
+    ID_DSA_WITH_SHA224 = "id-dsa-with-sha224", "2.16.840.1.101.3.4.3.1";
+    ID_DSA_WITH_SHA256 = "id-dsa-with-sha256", "2.16.840.1.101.3.4.3.2";
+    ID_ML_DSA_44 = "id-ml-dsa-44", "2.16.840.1.101.3.4.3.17";
+    ID_ML_DSA_65 = "id-ml-dsa-65", "2.16.840.1.101.3.4.3.18";
+    ID_ML_DSA_87 = "id-ml-dsa-87", "2.16.840.1.101.3.4.3.19";
+    ID_ALG_ML_KEM_512 = "id-alg-ml-kem-512", "2.16.840.1.101.3.4.4.1";
+    ID_ALG_ML_KEM_768 = "id-alg-ml-kem-768", "2.16.840.1.101.3.4.4.2";
+    ID_ALG_ML_KEM_1024 = "id-alg-ml-kem-1024", "2.16.840.1.101.3.4.4.3";
+
+    // Elliptic curves and EC signatures
+    ID_EC_PUBLIC_KEY = "id-ecPublicKey", "1.2.840.10045.2.1";
+    ECDSA_WITH_SHA1 = "ecdsa-with-SHA1", "1.2.840.10045.4.1";
+    ECDSA_WITH_SHA224 = "ecdsa-with-SHA224", "1.2.840.10045.4.3.1";
+    ECDSA_WITH_SHA256 = "ecdsa-with-SHA256", "1.2.840.10045.4.3.2";
+    ECDSA_WITH_SHA384 = "ecdsa-with-SHA384", "1.2.840.10045.4.3.3";
+    ECDSA_WITH_SHA512 = "ecdsa-with-SHA512", "1.2.840.10045.4.3.4";
+    SECP192R1 = "secp192r1", "1.2.840.10045.3.1.1";
+    SECP256R1 = "secp256r1", "1.2.840.10045.3.1.7";
+    SECP224R1 = "secp224r1", "1.3.132.0.33";
+    SECP384R1 = "secp384r1", "1.3.132.0.34";
+    SECP521R1 = "secp521r1", "1.3.132.0.35";
+    SECP256K1 = "secp256k1", "1.3.132.0.10";
+    BRAINPOOL_P256R1 = "brainpoolP256r1", "1.3.36.3.3.2.8.1.1.7";
+    BRAINPOOL_P384R1 = "brainpoolP384r1", "1.3.36.3.3.2.8.1.1.11";
+    BRAINPOOL_P512R1 = "brainpoolP512r1", "1.3.36.3.3.2.8.1.1.13";
+    ID_X25519 = "id-X25519", "1.3.101.110";
+    ID_X448 = "id-X448", "1.3.101.111";
+    ID_ED25519 = "id-Ed25519", "1.3.101.112";
+    ID_ED448 = "id-Ed448", "1.3.101.113";
+
+    // SNMP
+    INTERNET = "internet", "1.3.6.1";
+    DIRECTORY = "directory", "1.3.6.1.1";
+    MGMT = "mgmt", "1.3.6.1.2";
+    MIB_2 = "mib-2", "1.3.6.1.2.1";
+    SYSTEM = "system", "1.3.6.1.2.1.1";
+    SYS_DESCR = "sysDescr", "1.3.6.1.2.1.1.1";
+    SYS_OBJECT_ID = "sysObjectID", "1.3.6.1.2.1.1.2";
+    SYS_UP_TIME = "sysUpTime", "1.3.6.1.2.1.1.3";
+    SYS_CONTACT = "sysContact", "1.3.6.1.2.1.1.4";
+    SYS_NAME = "sysName", "1.3.6.1.2.1.1.5";
+    SYS_LOCATION = "sysLocation", "1.3.6.1.2.1.1.6";
+    INTERFACES = "interfaces", "1.3.6.1.2.1.2";
+    AT = "at", "1.3.6.1.2.1.3";
+    IP = "ip", "1.3.6.1.2.1.4";
+    ICMP = "icmp", "1.3.6.1.2.1.5";
+    TCP = "tcp", "1.3.6.1.2.1.6";
+    UDP = "udp", "1.3.6.1.2.1.7";
+    TRANSMISSION = "transmission", "1.3.6.1.2.1.10";
+    SNMP = "snmp", "1.3.6.1.2.1.11";
+    HOST = "host", "1.3.6.1.2.1.25";
+    IF_MIB = "ifMIB", "1.3.6.1.2.1.31";
+    EXPERIMENTAL = "experimental", "1.3.6.1.3";
+    PRIVATE = "private", "1.3.6.1.4";
+    ENTERPRISES = "enterprises", "1.3.6.1.4.1";
+    SECURITY = "security", "1.3.6.1.5";
+    SNMP_V2 = "snmpV2", "1.3.6.1.6";
+    SNMP_MODULES = "snmpModules", "1.3.6.1.6.3";
+}
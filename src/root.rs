@@ -1,4 +1,6 @@
-use core::convert::TryFrom;
+use core::{convert::TryFrom, fmt};
+
+use crate::Arc;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Arc0 {
@@ -16,11 +18,28 @@ impl Arc0 {
             _ => Err(IllegalRootNodeError(())),
         }
     }
+
+    /// ASN.1 name of the arc, e.g. `joint-iso-itu-t`
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Arc0::ItuT => "itu-t",
+            Arc0::Iso => "iso",
+            Arc0::JointIsoItuT => "joint-iso-itu-t",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct IllegalRootNodeError(());
 
+impl fmt::Display for IllegalRootNodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("first arc must be 0, 1 or 2")
+    }
+}
+
+impl crate::error::Error for IllegalRootNodeError {}
+
 impl TryFrom<u8> for Arc0 {
     type Error = IllegalRootNodeError;
     fn try_from(value: u8) -> Result<Arc0, Self::Error> {
@@ -28,26 +47,34 @@ impl TryFrom<u8> for Arc0 {
     }
 }
 
+/// Largest value of the second arc.
+///
+/// Only [Arc0::JointIsoItuT] allows values above `39`.
+/// The first two arcs are encoded as a single subidentifier `80 + arc1`,
+/// which must fit into [Arc].
+pub const MAX_ARC1: Arc = Arc::MAX - 80;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Arc1(u8);
+pub struct Arc1(Arc);
 
 impl Arc1 {
     /// # Safety
-    /// value must be < 40
-    pub const unsafe fn new_unchecked(value: u8) -> Arc1 {
+    /// value must be <= [MAX_ARC1]
+    pub const unsafe fn new_unchecked(value: Arc) -> Arc1 {
         Arc1(value)
     }
 
-    pub const fn new(value: u8) -> Result<Arc1, IllegalArc1Error> {
-        match value {
-            0..=39 => unsafe {
-                Ok(Arc1::new_unchecked(value))
-            },
-            _ => Err(IllegalArc1Error(())),
+    /// Note, that values in range `40..=MAX_ARC1` are only allowed under [Arc0::JointIsoItuT],
+    /// see [RootOid::new](crate::RootOid::new)
+    pub const fn new(value: Arc) -> Result<Arc1, IllegalArc1Error> {
+        if value > MAX_ARC1 {
+            return Err(IllegalArc1Error(()));
         }
+        // SAFETY: just checked value is within range
+        unsafe { Ok(Arc1::new_unchecked(value)) }
     }
 
-    pub const fn as_u8(&self) -> u8 {
+    pub const fn as_arc(&self) -> Arc {
         self.0
     }
 }
@@ -55,15 +82,23 @@ impl Arc1 {
 #[derive(Debug, Clone)]
 pub struct IllegalArc1Error(());
 
-impl TryFrom<u8> for Arc1 {
+impl fmt::Display for IllegalArc1Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "second arc must not exceed {}", MAX_ARC1)
+    }
+}
+
+impl crate::error::Error for IllegalArc1Error {}
+
+impl TryFrom<Arc> for Arc1 {
     type Error = IllegalArc1Error;
-    fn try_from(value: u8) -> Result<Arc1, Self::Error> {
+    fn try_from(value: Arc) -> Result<Arc1, Self::Error> {
         Arc1::new(value)
     }
 }
 
-impl From<Arc1> for u8 {
+impl From<Arc1> for Arc {
     fn from(arc: Arc1) -> Self {
-        arc.as_u8()
+        arc.as_arc()
     }
 }
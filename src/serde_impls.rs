@@ -0,0 +1,258 @@
+//! `serde` support, enabled with `serde` feature
+//!
+//! Human-readable formats use dotted text, e.g. `"1.3.6.1"` and `".2.1"`.
+//! Binary formats use BER content bytes, same as [AbsoluteOid::as_bytes].
+//!
+//! Borrowed [AbsoluteOid] and [RelativeOid] can only be deserialized from borrowed bytes,
+//! so they don't work with human-readable formats.
+
+use core::fmt;
+
+use serde::{
+    de::{self, Deserialize, Deserializer, Visitor},
+    ser::{Serialize, Serializer},
+};
+
+use crate::{AbsoluteOid, ParseOptions, RelativeOid, RootOid};
+
+impl Serialize for AbsoluteOid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+impl Serialize for RelativeOid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+impl Serialize for RootOid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_absolute().serialize(serializer)
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for &'a AbsoluteOid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BorrowedVisitor;
+
+        impl<'de> Visitor<'de> for BorrowedVisitor {
+            type Value = &'de AbsoluteOid;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("borrowed BER-encoded absolute oid")
+            }
+
+            fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+                AbsoluteOid::from_bytes(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_bytes(BorrowedVisitor)
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for &'a RelativeOid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BorrowedVisitor;
+
+        impl<'de> Visitor<'de> for BorrowedVisitor {
+            type Value = &'de RelativeOid;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("borrowed BER-encoded relative oid")
+            }
+
+            fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+                RelativeOid::from_bytes(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_bytes(BorrowedVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for RootOid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct RootVisitor;
+
+        impl RootVisitor {
+            fn from_oid<E: de::Error>(oid: &AbsoluteOid) -> Result<RootOid, E> {
+                if !oid.tail().is_empty() {
+                    return Err(E::custom("expected only the first two arcs"));
+                }
+                Ok(oid.root())
+            }
+        }
+
+        impl<'de> Visitor<'de> for RootVisitor {
+            type Value = RootOid;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("root oid")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                let (root, mut arcs) = ParseOptions::new().parse_absolute(v).map_err(E::custom)?;
+                if arcs.next().is_some() {
+                    return Err(E::custom("expected only the first two arcs"));
+                }
+                Ok(root)
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                RootVisitor::from_oid(AbsoluteOid::from_bytes(v).map_err(E::custom)?)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(RootVisitor)
+        } else {
+            deserializer.deserialize_bytes(RootVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod owned {
+    use alloc::vec::Vec;
+    use core::{fmt, marker::PhantomData};
+
+    use serde::{
+        de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+        ser::{Serialize, Serializer},
+    };
+
+    use crate::{AbsoluteOid, AbsoluteOidVec, RelativeOid, RelativeOidVec, SmallAbsoluteOid, SmallRelativeOid};
+
+    impl Serialize for AbsoluteOidVec {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.as_oid().serialize(serializer)
+        }
+    }
+
+    impl Serialize for RelativeOidVec {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.as_oid().serialize(serializer)
+        }
+    }
+
+    impl Serialize for SmallAbsoluteOid {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.as_oid().serialize(serializer)
+        }
+    }
+
+    impl Serialize for SmallRelativeOid {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.as_oid().serialize(serializer)
+        }
+    }
+
+    /// Common deserialization of owned oids
+    trait OwnedOid: Sized + core::str::FromStr<Err = crate::OidParsingError> {
+        const EXPECTING: &'static str;
+
+        fn from_bytes(bytes: &[u8]) -> Result<Self, crate::OidDecodingError>;
+    }
+
+    impl OwnedOid for AbsoluteOidVec {
+        const EXPECTING: &'static str = "absolute oid";
+
+        fn from_bytes(bytes: &[u8]) -> Result<Self, crate::OidDecodingError> {
+            Ok(AbsoluteOidVec::from_oid(AbsoluteOid::from_bytes(bytes)?))
+        }
+    }
+
+    impl OwnedOid for RelativeOidVec {
+        const EXPECTING: &'static str = "relative oid";
+
+        fn from_bytes(bytes: &[u8]) -> Result<Self, crate::OidDecodingError> {
+            Ok(RelativeOidVec::from_oid(RelativeOid::from_bytes(bytes)?))
+        }
+    }
+
+    impl OwnedOid for SmallAbsoluteOid {
+        const EXPECTING: &'static str = "absolute oid";
+
+        fn from_bytes(bytes: &[u8]) -> Result<Self, crate::OidDecodingError> {
+            Ok(SmallAbsoluteOid::from_oid(AbsoluteOid::from_bytes(bytes)?))
+        }
+    }
+
+    impl OwnedOid for SmallRelativeOid {
+        const EXPECTING: &'static str = "relative oid";
+
+        fn from_bytes(bytes: &[u8]) -> Result<Self, crate::OidDecodingError> {
+            Ok(SmallRelativeOid::from_oid(RelativeOid::from_bytes(bytes)?))
+        }
+    }
+
+    struct OwnedVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: OwnedOid> Visitor<'de> for OwnedVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(T::EXPECTING)
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse().map_err(E::custom)
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            T::from_bytes(v).map_err(E::custom)
+        }
+
+        // some binary formats serialize bytes as a sequence
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+            self.visit_bytes(&bytes)
+        }
+    }
+
+    fn deserialize_owned<'de, T: OwnedOid, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(OwnedVisitor(PhantomData))
+        } else {
+            deserializer.deserialize_bytes(OwnedVisitor(PhantomData))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for AbsoluteOidVec {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize_owned(deserializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for RelativeOidVec {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize_owned(deserializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for SmallAbsoluteOid {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize_owned(deserializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for SmallRelativeOid {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize_owned(deserializer)
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Ordered set of oids, with prefix coverage
+
+use core::{fmt, iter::FromIterator};
+
+use crate::{AbsoluteOid, AbsoluteOidVec, OidMap, OidMapIter, StaticAbsoluteOid};
+
+/// Set of [AbsoluteOid], ordered by arcs
+///
+/// Set operations work on members, not on subtrees, e.g. `{1.3}` and `{1.3.6}` don't intersect.
+/// ```
+/// # use oid_str::{oid, OidSet, StaticAbsoluteOid};
+/// const PKIX: StaticAbsoluteOid<'static> = oid!("1.3.6.1.5.5.7");
+/// const RSA: StaticAbsoluteOid<'static> = oid!("1.2.840.113549.1.1");
+///
+/// let allowed: OidSet = vec![PKIX, RSA].into_iter().collect();
+/// assert!(allowed.covers(&oid!("1.2.840.113549.1.1.11")));
+/// assert!(!allowed.covers(&oid!("1.2.840.10045.4.3.2")));
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct OidSet {
+    map: OidMap<()>,
+}
+
+impl OidSet {
+    pub fn new() -> OidSet {
+        OidSet { map: OidMap::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// Adds an oid, returns whether it was not present
+    pub fn insert(&mut self, oid: &AbsoluteOid) -> bool {
+        self.map.insert(oid, ()).is_none()
+    }
+
+    /// Removes an oid, returns whether it was present
+    pub fn remove(&mut self, oid: &AbsoluteOid) -> bool {
+        self.map.remove(oid).is_some()
+    }
+
+    pub fn contains(&self, oid: &AbsoluteOid) -> bool {
+        self.map.contains_key(oid)
+    }
+
+    /// Whether any member is a prefix of `oid` (including `oid` itself)
+    pub fn covers(&self, oid: &AbsoluteOid) -> bool {
+        self.covering(oid).is_some()
+    }
+
+    /// The longest member, that is a prefix of `oid`
+    pub fn covering<'a>(&'a self, oid: &AbsoluteOid) -> Option<&'a AbsoluteOid> {
+        self.map.longest_prefix(oid).map(|(key, _)| key)
+    }
+
+    /// Removes members, that are covered by other members
+    ///
+    /// Result covers exactly the same oids.
+    pub fn minimize(&mut self) {
+        let mut minimal = OidSet::new();
+        let mut last: Option<&AbsoluteOid> = None;
+        // an ancestor goes right before its subtree,
+        // so a member is covered only if it starts with the last kept one
+        for oid in self.iter() {
+            if !last.is_some_and(|last| oid.starts_with(last)) {
+                minimal.insert(oid);
+                last = Some(oid);
+            }
+        }
+        *self = minimal;
+    }
+
+    pub fn union(&self, other: &OidSet) -> OidSet {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    pub fn intersection(&self, other: &OidSet) -> OidSet {
+        self.iter().filter(|oid| other.contains(oid)).collect()
+    }
+
+    pub fn difference(&self, other: &OidSet) -> OidSet {
+        self.iter().filter(|oid| !other.contains(oid)).collect()
+    }
+
+    /// All members in arc order
+    pub fn iter(&self) -> OidSetIter<'_> {
+        OidSetIter {
+            iter: self.map.iter(),
+        }
+    }
+}
+
+impl fmt::Debug for OidSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> IntoIterator for &'a OidSet {
+    type Item = &'a AbsoluteOid;
+    type IntoIter = OidSetIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> Extend<&'a AbsoluteOid> for OidSet {
+    fn extend<I: IntoIterator<Item = &'a AbsoluteOid>>(&mut self, iter: I) {
+        for oid in iter {
+            self.insert(oid);
+        }
+    }
+}
+
+impl<'a> Extend<StaticAbsoluteOid<'a>> for OidSet {
+    fn extend<I: IntoIterator<Item = StaticAbsoluteOid<'a>>>(&mut self, iter: I) {
+        for oid in iter {
+            self.insert(&oid);
+        }
+    }
+}
+
+impl Extend<AbsoluteOidVec> for OidSet {
+    fn extend<I: IntoIterator<Item = AbsoluteOidVec>>(&mut self, iter: I) {
+        self.map.extend(iter.into_iter().map(|oid| (oid, ())))
+    }
+}
+
+impl<'a> FromIterator<&'a AbsoluteOid> for OidSet {
+    fn from_iter<I: IntoIterator<Item = &'a AbsoluteOid>>(iter: I) -> Self {
+        let mut set = OidSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a> FromIterator<StaticAbsoluteOid<'a>> for OidSet {
+    fn from_iter<I: IntoIterator<Item = StaticAbsoluteOid<'a>>>(iter: I) -> Self {
+        let mut set = OidSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl FromIterator<AbsoluteOidVec> for OidSet {
+    fn from_iter<I: IntoIterator<Item = AbsoluteOidVec>>(iter: I) -> Self {
+        let mut set = OidSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+/// Iterator over members of [OidSet], see [OidSet::iter]
+pub struct OidSetIter<'a> {
+    iter: OidMapIter<'a, ()>,
+}
+
+impl<'a> Iterator for OidSetIter<'a> {
+    type Item = &'a AbsoluteOid;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(oid, _)| oid)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
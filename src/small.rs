@@ -0,0 +1,286 @@
+//! Owned oids, that are stored inline while short and spill to the heap otherwise
+
+use alloc::vec::Vec;
+
+use crate::{
+    AbsoluteOid, AbsoluteOidArray, AbsoluteOidVec, Arc, RelativeOid, RelativeOidArray, RelativeOidVec, RootOid,
+};
+
+/// Number of encoded bytes, that small oids store without allocating
+pub const SMALL_OID_INLINE_LEN: usize = 24;
+
+#[derive(Clone)]
+enum RelativeRepr {
+    Inline(RelativeOidArray<SMALL_OID_INLINE_LEN>),
+    Heap(RelativeOidVec),
+}
+
+/// Same as [RelativeOidVec], but doesn't allocate up to [SMALL_OID_INLINE_LEN] bytes
+///
+/// Compares and hashes the same way as [RelativeOid].
+#[derive(Clone)]
+pub struct SmallRelativeOid {
+    repr: RelativeRepr,
+}
+
+impl Default for SmallRelativeOid {
+    fn default() -> Self {
+        SmallRelativeOid {
+            repr: RelativeRepr::Inline(RelativeOidArray::new()),
+        }
+    }
+}
+
+impl core::ops::Deref for SmallRelativeOid {
+    type Target = RelativeOid;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_oid()
+    }
+}
+
+impl core::ops::DerefMut for SmallRelativeOid {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_oid()
+    }
+}
+
+impl SmallRelativeOid {
+    pub fn from_oid(oid: &RelativeOid) -> SmallRelativeOid {
+        let repr = match RelativeOidArray::from_oid(oid) {
+            Ok(array) => RelativeRepr::Inline(array),
+            Err(_) => RelativeRepr::Heap(RelativeOidVec::from_oid(oid)),
+        };
+        SmallRelativeOid { repr }
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        match self.repr {
+            RelativeRepr::Inline(array) => array.as_bytes().to_vec(),
+            RelativeRepr::Heap(vec) => vec.into_bytes(),
+        }
+    }
+
+    /// Whether the oid is stored without allocation
+    pub fn is_inline(&self) -> bool {
+        matches!(self.repr, RelativeRepr::Inline(_))
+    }
+
+    pub fn as_oid(&self) -> &RelativeOid {
+        match &self.repr {
+            RelativeRepr::Inline(array) => array.as_oid(),
+            RelativeRepr::Heap(vec) => vec.as_oid(),
+        }
+    }
+
+    pub fn as_mut_oid(&mut self) -> &mut RelativeOid {
+        match &mut self.repr {
+            RelativeRepr::Inline(array) => array.as_mut_oid(),
+            RelativeRepr::Heap(vec) => vec.as_mut_oid(),
+        }
+    }
+
+    pub fn push(&mut self, arc: Arc) {
+        if let RelativeRepr::Inline(array) = &mut self.repr {
+            if array.push(arc).is_ok() {
+                return;
+            }
+        }
+        self.spill().push(arc)
+    }
+
+    pub fn extend(&mut self, oid: &RelativeOid) {
+        if let RelativeRepr::Inline(array) = &mut self.repr {
+            if array.extend(oid).is_ok() {
+                return;
+            }
+        }
+        self.spill().extend(oid)
+    }
+
+    /// Moves inline bytes to the heap
+    fn spill(&mut self) -> &mut RelativeOidVec {
+        if let RelativeRepr::Inline(array) = &self.repr {
+            self.repr = RelativeRepr::Heap(RelativeOidVec::from_oid(array));
+        }
+        match &mut self.repr {
+            RelativeRepr::Heap(vec) => vec,
+            RelativeRepr::Inline(_) => unreachable!(),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum AbsoluteRepr {
+    Inline(AbsoluteOidArray<SMALL_OID_INLINE_LEN>),
+    Heap(AbsoluteOidVec),
+}
+
+/// Same as [AbsoluteOidVec], but doesn't allocate up to [SMALL_OID_INLINE_LEN] bytes
+///
+/// Compares and hashes the same way as [AbsoluteOid].
+/// ```
+/// # use oid_str::SmallAbsoluteOid;
+/// let mut oid: SmallAbsoluteOid = "1.2.840.113549.1.1.11".parse().unwrap();
+/// assert!(oid.is_inline());
+/// for _ in 0..8 {
+///     oid.push(u32::MAX.into());
+/// }
+/// assert!(!oid.is_inline());
+/// ```
+#[derive(Clone)]
+pub struct SmallAbsoluteOid {
+    repr: AbsoluteRepr,
+}
+
+impl core::ops::Deref for SmallAbsoluteOid {
+    type Target = AbsoluteOid;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_oid()
+    }
+}
+
+impl core::ops::DerefMut for SmallAbsoluteOid {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_oid()
+    }
+}
+
+impl SmallAbsoluteOid {
+    pub fn from_root(root: RootOid) -> Self {
+        SmallAbsoluteOid::from_oid(root.as_absolute())
+    }
+
+    pub fn from_oid(oid: &AbsoluteOid) -> SmallAbsoluteOid {
+        let repr = match AbsoluteOidArray::from_oid(oid) {
+            Ok(array) => AbsoluteRepr::Inline(array),
+            Err(_) => AbsoluteRepr::Heap(AbsoluteOidVec::from_oid(oid)),
+        };
+        SmallAbsoluteOid { repr }
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        match self.repr {
+            AbsoluteRepr::Inline(array) => array.as_bytes().to_vec(),
+            AbsoluteRepr::Heap(vec) => vec.into_bytes(),
+        }
+    }
+
+    /// Whether the oid is stored without allocation
+    pub fn is_inline(&self) -> bool {
+        matches!(self.repr, AbsoluteRepr::Inline(_))
+    }
+
+    pub fn as_oid(&self) -> &AbsoluteOid {
+        match &self.repr {
+            AbsoluteRepr::Inline(array) => array.as_oid(),
+            AbsoluteRepr::Heap(vec) => vec.as_oid(),
+        }
+    }
+
+    pub fn as_mut_oid(&mut self) -> &mut AbsoluteOid {
+        match &mut self.repr {
+            AbsoluteRepr::Inline(array) => array.as_mut_oid(),
+            AbsoluteRepr::Heap(vec) => vec.as_mut_oid(),
+        }
+    }
+
+    pub fn push(&mut self, arc: Arc) {
+        if let AbsoluteRepr::Inline(array) = &mut self.repr {
+            if array.push(arc).is_ok() {
+                return;
+            }
+        }
+        self.spill().push(arc)
+    }
+
+    pub fn extend(&mut self, oid: &RelativeOid) {
+        if let AbsoluteRepr::Inline(array) = &mut self.repr {
+            if array.extend(oid).is_ok() {
+                return;
+            }
+        }
+        self.spill().extend(oid)
+    }
+
+    /// Moves inline bytes to the heap
+    fn spill(&mut self) -> &mut AbsoluteOidVec {
+        if let AbsoluteRepr::Inline(array) = &self.repr {
+            self.repr = AbsoluteRepr::Heap(AbsoluteOidVec::from_oid(array));
+        }
+        match &mut self.repr {
+            AbsoluteRepr::Heap(vec) => vec,
+            AbsoluteRepr::Inline(_) => unreachable!(),
+        }
+    }
+}
+
+// small oids compare and hash as the borrowed oids, regardless of where bytes are stored
+macro_rules! impl_small_eq {
+    ($($ty:ty),*) => {$(
+        impl PartialEq for $ty {
+            fn eq(&self, other: &Self) -> bool {
+                **self == **other
+            }
+        }
+
+        impl Eq for $ty {}
+
+        impl core::hash::Hash for $ty {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                (**self).hash(state)
+            }
+        }
+    )*};
+}
+
+impl_small_eq!(SmallAbsoluteOid, SmallRelativeOid);
+
+impl From<&AbsoluteOid> for SmallAbsoluteOid {
+    fn from(oid: &AbsoluteOid) -> SmallAbsoluteOid {
+        SmallAbsoluteOid::from_oid(oid)
+    }
+}
+
+impl From<&RelativeOid> for SmallRelativeOid {
+    fn from(oid: &RelativeOid) -> SmallRelativeOid {
+        SmallRelativeOid::from_oid(oid)
+    }
+}
+
+/// Keeps the allocation
+impl From<AbsoluteOidVec> for SmallAbsoluteOid {
+    fn from(vec: AbsoluteOidVec) -> SmallAbsoluteOid {
+        SmallAbsoluteOid {
+            repr: AbsoluteRepr::Heap(vec),
+        }
+    }
+}
+
+/// Keeps the allocation
+impl From<RelativeOidVec> for SmallRelativeOid {
+    fn from(vec: RelativeOidVec) -> SmallRelativeOid {
+        SmallRelativeOid {
+            repr: RelativeRepr::Heap(vec),
+        }
+    }
+}
+
+impl From<SmallAbsoluteOid> for AbsoluteOidVec {
+    fn from(small: SmallAbsoluteOid) -> AbsoluteOidVec {
+        match small.repr {
+            AbsoluteRepr::Inline(array) => AbsoluteOidVec::from_oid(&array),
+            AbsoluteRepr::Heap(vec) => vec,
+        }
+    }
+}
+
+impl From<SmallRelativeOid> for RelativeOidVec {
+    fn from(small: SmallRelativeOid) -> RelativeOidVec {
+        match small.repr {
+            RelativeRepr::Inline(array) => RelativeOidVec::from_oid(&array),
+            RelativeRepr::Heap(vec) => vec,
+        }
+    }
+}
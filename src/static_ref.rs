@@ -29,11 +29,37 @@ impl<'a> StaticAbsoluteOid<'a> {
         StaticAbsoluteOid { bytes }
     }
 
-    pub fn from_bytes(bytes: &'a [u8]) -> Result<StaticAbsoluteOid<'a>, OidDecodingError> {
-        AbsoluteOid::check_bytes(bytes)?;
+    pub const fn from_bytes(bytes: &'a [u8]) -> Result<StaticAbsoluteOid<'a>, OidDecodingError> {
+        if let Err(error) = AbsoluteOid::check_bytes(bytes) {
+            return Err(error);
+        }
         // SAFETY: check above ensures invariants of AbsoluteOid are satisfied
         Ok(unsafe { StaticAbsoluteOid::from_bytes_unchecked(bytes) })
     }
+
+    /// Same as [from_bytes](StaticAbsoluteOid::from_bytes), but panics on invalid bytes
+    ///
+    /// Intended for constants, where the panic fails the build
+    /// ```
+    /// # use oid_str::StaticAbsoluteOid;
+    /// const OID: StaticAbsoluteOid<'static> = StaticAbsoluteOid::from_bytes_const(b"\x2b\x06\x01");
+    /// ```
+    /// ```compile_fail
+    /// # use oid_str::StaticAbsoluteOid;
+    /// const OID: StaticAbsoluteOid<'static> = StaticAbsoluteOid::from_bytes_const(b"\x2b\x86");
+    /// ```
+    pub const fn from_bytes_const(bytes: &'a [u8]) -> StaticAbsoluteOid<'a> {
+        match StaticAbsoluteOid::from_bytes(bytes) {
+            Ok(oid) => oid,
+            Err(_) => panic!("invalid AbsoluteOid bytes"),
+        }
+    }
+
+    /// Same as [AbsoluteOid::as_bytes], but usable in `const fn`
+    #[cfg(feature = "registry")]
+    pub(crate) const fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
 }
 
 
@@ -62,11 +88,31 @@ impl<'a> StaticRelativeOid<'a> {
         StaticRelativeOid { bytes }
     }
 
-    pub fn from_bytes(bytes: &'a [u8]) -> Result<StaticRelativeOid<'a>, OidDecodingError> {
-        RelativeOid::check_bytes(bytes)?;
+    pub const fn from_bytes(bytes: &'a [u8]) -> Result<StaticRelativeOid<'a>, OidDecodingError> {
+        if let Err(error) = RelativeOid::check_bytes(bytes) {
+            return Err(OidDecodingError::Base128(error));
+        }
         // SAFETY: check above ensures invariants of RelativeOid are satisfied
         Ok(unsafe { StaticRelativeOid::from_bytes_unchecked(bytes) })
     }
+
+    /// Same as [from_bytes](StaticRelativeOid::from_bytes), but panics on invalid bytes
+    ///
+    /// Intended for constants, where the panic fails the build
+    /// ```
+    /// # use oid_str::StaticRelativeOid;
+    /// const OID: StaticRelativeOid<'static> = StaticRelativeOid::from_bytes_const(b"\x2b\x06\x01");
+    /// ```
+    /// ```compile_fail
+    /// # use oid_str::StaticRelativeOid;
+    /// const OID: StaticRelativeOid<'static> = StaticRelativeOid::from_bytes_const(b"\x2b\x86");
+    /// ```
+    pub const fn from_bytes_const(bytes: &'a [u8]) -> StaticRelativeOid<'a> {
+        match StaticRelativeOid::from_bytes(bytes) {
+            Ok(oid) => oid,
+            Err(_) => panic!("invalid RelativeOid bytes"),
+        }
+    }
 }
 
 
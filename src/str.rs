@@ -1,23 +1,32 @@
-use core::fmt;
+use core::{convert::TryFrom, fmt, ops::Range};
 
-use crate::{AbsoluteOid, Arc, Arc0, Arc1, RelativeOid, RootOid};
+use crate::{
+    encode::{b128_len, encode_b128},
+    named::{write_named, DefaultNames},
+    AbsoluteOid, AbsoluteOidArray, Arc, Arc0, Arc1, BufferTooSmallError, RelativeOid, RelativeOidArray, RootOid,
+};
 
+/// `{:#}` annotates arcs with names, see [AbsoluteOid::display_with]
 impl fmt::Display for AbsoluteOid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return write_named(self, &DefaultNames, ".", f);
+        }
         write!(f, "{}{}", self.root(), self.tail())
     }
 }
 
+/// Always plain dotted text, `{:#?}` does not print names
 impl fmt::Debug for AbsoluteOid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(self, f)
+        write!(f, "{}{}", self.root(), self.tail())
     }
 }
 
 impl fmt::Display for RootOid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let (arc0, arc1) = self.into_arcs();
-        write!(f, "{}.{}", arc0 as u8, arc1.as_u8())
+        write!(f, "{}.{}", arc0 as u8, arc1.as_arc())
     }
 }
 
@@ -44,67 +53,176 @@ impl fmt::Debug for RelativeOid {
 
 pub struct StrArcs<'a> {
     parts: core::str::Split<'a, char>,
+    /// Position of the next arc in the parsed text
+    pos: usize,
 }
 
 impl<'a> StrArcs<'a> {
-    fn new(s: &'a str) -> Self {
+    /// Arcs of `s`, which starts at `pos` of the parsed text
+    fn new(s: &'a str, pos: usize) -> Self {
         Self {
             parts: s.split('.'),
+            pos,
         }
     }
-}
 
-impl core::iter::Iterator for StrArcs<'_> {
-    type Item = Result<Arc, OidParsingError>;
+    /// Moves error positions by `offset`, for arcs in a part of the input
+    fn shifted(mut self, offset: usize) -> Self {
+        self.pos += offset;
+        self
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Next arc with its byte range in the parsed text
+    fn next_with_range(&mut self) -> Option<(Result<Arc, OidParsingError>, Range<usize>)> {
         let value = self.parts.next()?;
         if value.is_empty() {
             return None;
         }
-        Some(value.parse().map_err(|_| OidParsingError::OverflowError))
+        let range = self.pos..self.pos + value.len();
+        self.pos = range.end + 1;
+        let arc = value.parse().map_err(|_| OidParsingError::OverflowError(range.clone()));
+        Some((arc, range))
     }
 }
 
-pub fn parse_absolute(s: &str) -> Result<(RootOid, StrArcs), OidParsingError> {
+impl<'a> StrArcs<'a> {
+    /// Remaining arcs as text, without parsing them into [Arc]
+    #[cfg(feature = "alloc")]
+    pub(crate) fn into_parts(self) -> core::str::Split<'a, char> {
+        self.parts
+    }
+}
+
+impl core::iter::Iterator for StrArcs<'_> {
+    type Item = Result<Arc, OidParsingError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_range().map(|(arc, _)| arc)
+    }
+}
+
+/// Lenient parsing of dotted text, same as [ParseOptions::lenient]
+pub fn parse_absolute(s: &str) -> Result<(RootOid, StrArcs<'_>), OidParsingError> {
     check_not_empty(s)?;
     check_str(s)?;
 
-    let s = strip_leading_dot(s);
-    let mut parts = s.split('.');
+    let body = strip_leading_dot(s);
+    let mut arcs = StrArcs::new(body, s.len() - body.len());
 
     // must have at least on segment if `check_not_empty` has passed
-    let arc0_str = parts.next().unwrap();
-    let arc0 = arc0_str
-        .parse()
-        .map_err(|_| OidParsingError::OverflowError)?;
-    let arc0 = Arc0::new(arc0).map_err(|_| OidParsingError::OverflowError)?;
-    let arc1_str = parts.next().ok_or(OidParsingError::NoArc1)?;
-    let arc1 = arc1_str
-        .parse()
-        .map_err(|_| OidParsingError::OverflowError)?;
-    let arc1 = Arc1::new(arc1).map_err(|_| OidParsingError::OverflowError)?;
-
-    let root = RootOid::new(arc0, arc1);
-    let arc = StrArcs { parts };
+    let (arc0, range0) = arcs.next_with_range().unwrap();
+    let arc0 = arc0
+        .ok()
+        .and_then(|arc0| u8::try_from(arc0).ok())
+        .and_then(|arc0| Arc0::new(arc0).ok())
+        .ok_or(OidParsingError::InvalidArc0(range0))?;
+    let (arc1, range1) = arcs.next_with_range().ok_or(OidParsingError::NoArc1)?;
+    let root = arc1
+        .ok()
+        .and_then(|arc1| Arc1::new(arc1).ok())
+        .and_then(|arc1| RootOid::new(arc0, arc1).ok())
+        .ok_or(OidParsingError::InvalidArc1(range1))?;
+
+    Ok((root, arcs))
+}
 
-    Ok((root, arc))
+/// Same as [parse_absolute], but for `urn:oid:` URNs of RFC 3061, e.g. `urn:oid:1.3.6.1`
+///
+/// The prefix is case-insensitive. Unlike [parse_absolute], a leading dot and leading zeros
+/// are rejected. Error positions point into `s`, including the prefix.
+/// ```
+/// # use oid_str::{parse_urn, OidParsingError};
+/// let (root, mut arcs) = parse_urn("URN:OID:1.3.6").unwrap();
+/// assert_eq!(root.to_string(), "1.3");
+/// assert_eq!(arcs.next().unwrap().unwrap(), 6);
+///
+/// assert!(matches!(parse_urn("urn:oid:1.3.x").err().unwrap(), OidParsingError::InvalidChar(12)));
+/// ```
+pub fn parse_urn(s: &str) -> Result<(RootOid, StrArcs<'_>), OidParsingError> {
+    let nss = strip_urn_prefix(s)?;
+    let offset = URN_PREFIX.len();
+    check_urn_numbers(nss).map_err(|error| error.shifted(offset))?;
+    parse_absolute(nss)
+        .map(|(root, arcs)| (root, arcs.shifted(offset)))
+        .map_err(|error| error.shifted(offset))
 }
 
-pub fn parse_relative(s: &str) -> Result<StrArcs, OidParsingError> {
+/// Lenient parsing of dotted text, same as [ParseOptions::lenient]
+pub fn parse_relative(s: &str) -> Result<StrArcs<'_>, OidParsingError> {
     check_str(s)?;
 
-    let s = strip_leading_dot(s);
-    Ok(StrArcs::new(s))
+    let body = strip_leading_dot(s);
+    Ok(StrArcs::new(body, s.len() - body.len()))
 }
 #[derive(Debug, Clone)]
 pub enum OidParsingError {
+    /// Byte position of a character, that is not a digit or a dot
     InvalidChar(usize),
     IntegerExpected(usize),
-    OverflowError,
+    /// Byte range of an arc, that does not fit into [Arc]
+    OverflowError(Range<usize>),
+    /// Byte range of the first arc, that is not one of `0, 1, 2`
+    InvalidArc0(Range<usize>),
+    /// Byte range of the second arc, that is out of range under the first one, see [Arc1]
+    InvalidArc1(Range<usize>),
     NoArc1,
+    /// Input does not start with `urn:oid:`, position of the first mismatch
+    UrnPrefixExpected(usize),
+    /// Arc has leading zeros, position of the first zero
+    LeadingZero(usize),
+    /// Text must start with a dot, see [LeadingDot::Required]
+    LeadingDotExpected(usize),
+    /// Text must not start with a dot, see [LeadingDot::Forbidden]
+    UnexpectedLeadingDot(usize),
 }
 
+impl OidParsingError {
+    /// Moves position by `offset`, for errors in a part of the input
+    fn shifted(self, offset: usize) -> OidParsingError {
+        match self {
+            OidParsingError::InvalidChar(pos) => OidParsingError::InvalidChar(pos + offset),
+            OidParsingError::IntegerExpected(pos) => OidParsingError::IntegerExpected(pos + offset),
+            OidParsingError::UrnPrefixExpected(pos) => OidParsingError::UrnPrefixExpected(pos + offset),
+            OidParsingError::LeadingZero(pos) => OidParsingError::LeadingZero(pos + offset),
+            OidParsingError::LeadingDotExpected(pos) => OidParsingError::LeadingDotExpected(pos + offset),
+            OidParsingError::UnexpectedLeadingDot(pos) => OidParsingError::UnexpectedLeadingDot(pos + offset),
+            OidParsingError::OverflowError(range) => {
+                OidParsingError::OverflowError(range.start + offset..range.end + offset)
+            }
+            OidParsingError::InvalidArc0(range) => OidParsingError::InvalidArc0(range.start + offset..range.end + offset),
+            OidParsingError::InvalidArc1(range) => OidParsingError::InvalidArc1(range.start + offset..range.end + offset),
+            error => error,
+        }
+    }
+}
+
+impl fmt::Display for OidParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OidParsingError::InvalidChar(pos) => write!(f, "invalid character at position {}", pos),
+            OidParsingError::IntegerExpected(pos) => write!(f, "expected an arc at position {}", pos),
+            OidParsingError::OverflowError(range) => {
+                write!(f, "arc at positions {}..{} is out of range", range.start, range.end)
+            }
+            OidParsingError::InvalidArc0(range) => {
+                write!(f, "first arc at positions {}..{} must be 0, 1 or 2", range.start, range.end)
+            }
+            OidParsingError::InvalidArc1(range) => {
+                write!(f, "second arc at positions {}..{} is out of range", range.start, range.end)
+            }
+            OidParsingError::NoArc1 => f.write_str("absolute oid must have at least two arcs"),
+            OidParsingError::UrnPrefixExpected(pos) => {
+                write!(f, "expected `urn:oid:` prefix, mismatch at position {}", pos)
+            }
+            OidParsingError::LeadingZero(pos) => write!(f, "arc with leading zeros at position {}", pos),
+            OidParsingError::LeadingDotExpected(pos) => write!(f, "expected a leading dot at position {}", pos),
+            OidParsingError::UnexpectedLeadingDot(pos) => write!(f, "unexpected leading dot at position {}", pos),
+        }
+    }
+}
+
+impl crate::error::Error for OidParsingError {}
+
 fn check_not_empty(s: &str) -> Result<(), OidParsingError> {
     if s.is_empty() || s == "." {
         return Err(OidParsingError::IntegerExpected(0));
@@ -113,7 +231,7 @@ fn check_not_empty(s: &str) -> Result<(), OidParsingError> {
 }
 
 fn check_str(s: &str) -> Result<(), OidParsingError> {
-    if let Some(p) = s.chars().position(|c| !c.is_digit(10) && c != '.') {
+    if let Some((p, _)) = s.char_indices().find(|(_, c)| !c.is_ascii_digit() && c != &'.') {
         return Err(OidParsingError::InvalidChar(p));
     }
     if let Some(p) = s.find("..") {
@@ -125,8 +243,335 @@ fn check_str(s: &str) -> Result<(), OidParsingError> {
     Ok(())
 }
 
+const URN_PREFIX: &str = "urn:oid:";
+
+fn strip_urn_prefix(s: &str) -> Result<&str, OidParsingError> {
+    let bytes = s.as_bytes();
+    for (pos, expected) in URN_PREFIX.bytes().enumerate() {
+        match bytes.get(pos) {
+            Some(c) if c.eq_ignore_ascii_case(&expected) => {}
+            _ => return Err(OidParsingError::UrnPrefixExpected(pos)),
+        }
+    }
+    Ok(&s[URN_PREFIX.len()..])
+}
+
+/// RFC 3061 numbers can't be empty or have leading zeros, so there is no leading dot either
+fn check_urn_numbers(nss: &str) -> Result<(), OidParsingError> {
+    let bytes = nss.as_bytes();
+    let mut start = 0;
+    for number in nss.split('.') {
+        if number.is_empty() {
+            return Err(OidParsingError::IntegerExpected(start));
+        }
+        if number.len() > 1 && bytes[start] == b'0' && bytes[start + 1].is_ascii_digit() {
+            return Err(OidParsingError::LeadingZero(start));
+        }
+        start += number.len() + 1;
+    }
+    Ok(())
+}
+
+/// Formats an oid as `urn:oid:` URN, see [AbsoluteOid::urn]
+pub struct Urn<'a> {
+    oid: &'a AbsoluteOid,
+}
+
+impl AbsoluteOid {
+    /// `urn:oid:` URN of RFC 3061, as a display adapter
+    /// ```
+    /// # use oid_str::AbsoluteOid;
+    /// let oid = AbsoluteOid::from_bytes(b"\x2b\x06\x01").unwrap();
+    /// assert_eq!(oid.urn().to_string(), "urn:oid:1.3.6.1");
+    /// ```
+    pub fn urn(&self) -> Urn<'_> {
+        Urn { oid: self }
+    }
+
+    /// Same as [AbsoluteOid::urn], but allocates a string
+    #[cfg(feature = "alloc")]
+    pub fn to_urn(&self) -> alloc::string::String {
+        alloc::string::ToString::to_string(&self.urn())
+    }
+}
+
+impl fmt::Display for Urn<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", URN_PREFIX, self.oid)
+    }
+}
+
+/// Whether text may start with a dot, e.g. `.1.3.6`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeadingDot {
+    Optional,
+    Required,
+    Forbidden,
+}
+
+/// Options of dotted text parsing
+///
+/// Default is strict X.660 form: arcs without leading zeros, optional leading dot,
+/// no surrounding whitespace. This is what `FromStr` of [AbsoluteOidVec](crate::AbsoluteOidVec)
+/// and [RelativeOidVec](crate::RelativeOidVec) uses.
+/// ```
+/// # use oid_str::{LeadingDot, OidParsingError, ParseOptions};
+/// let strict = ParseOptions::new();
+/// assert!(matches!(strict.parse_absolute("1.03.6").err().unwrap(), OidParsingError::LeadingZero(2)));
+///
+/// let (root, _) = ParseOptions::lenient().parse_absolute("1.03.6").unwrap();
+/// assert_eq!(root.to_string(), "1.3");
+///
+/// let options = ParseOptions::new().trim_whitespace(true).leading_dot(LeadingDot::Required);
+/// assert_eq!(options.parse_relative(" .2.1\n").unwrap().count(), 2);
+/// assert!(options.parse_relative("2.1").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub(crate) leading_zeros: bool,
+    pub(crate) leading_dot: LeadingDot,
+    trim_whitespace: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::new()
+    }
+}
+
+impl ParseOptions {
+    /// Strict options
+    pub const fn new() -> ParseOptions {
+        ParseOptions {
+            leading_zeros: false,
+            leading_dot: LeadingDot::Optional,
+            trim_whitespace: false,
+        }
+    }
+
+    /// Same as [ParseOptions::new], but leading zeros are ignored, e.g. `1.03.6` is `1.3.6`
+    pub const fn lenient() -> ParseOptions {
+        ParseOptions::new().leading_zeros(true)
+    }
+
+    /// Whether arcs may have leading zeros
+    pub const fn leading_zeros(mut self, allow: bool) -> ParseOptions {
+        self.leading_zeros = allow;
+        self
+    }
+
+    pub const fn leading_dot(mut self, leading_dot: LeadingDot) -> ParseOptions {
+        self.leading_dot = leading_dot;
+        self
+    }
+
+    /// Whether to ignore whitespace around the text, error positions still point into the whole text
+    pub const fn trim_whitespace(mut self, trim: bool) -> ParseOptions {
+        self.trim_whitespace = trim;
+        self
+    }
+
+    pub fn parse_absolute<'a>(&self, s: &'a str) -> Result<(RootOid, StrArcs<'a>), OidParsingError> {
+        let (s, offset) = self.trim(s);
+        self.check_dot(s)
+            .and_then(|_| parse_absolute(s))
+            .and_then(|parsed| self.check_zeros(s).map(|_| parsed))
+            .map(|(root, arcs)| (root, arcs.shifted(offset)))
+            .map_err(|error| error.shifted(offset))
+    }
+
+    pub fn parse_relative<'a>(&self, s: &'a str) -> Result<StrArcs<'a>, OidParsingError> {
+        let (s, offset) = self.trim(s);
+        self.check_dot(s)
+            .and_then(|_| parse_relative(s))
+            .and_then(|parsed| self.check_zeros(s).map(|_| parsed))
+            .map(|arcs| arcs.shifted(offset))
+            .map_err(|error| error.shifted(offset))
+    }
+
+    /// Trimmed text and its position in `s`
+    pub(crate) fn trim<'a>(&self, s: &'a str) -> (&'a str, usize) {
+        if !self.trim_whitespace {
+            return (s, 0);
+        }
+        let trimmed = s.trim_start();
+        (trimmed.trim_end(), s.len() - trimmed.len())
+    }
+
+    fn check_dot(&self, s: &str) -> Result<(), OidParsingError> {
+        let has_dot = s.starts_with('.');
+        match self.leading_dot {
+            LeadingDot::Required if !has_dot && !s.is_empty() => Err(OidParsingError::LeadingDotExpected(0)),
+            LeadingDot::Forbidden if has_dot => Err(OidParsingError::UnexpectedLeadingDot(0)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Expects text that is already checked to be digits and dots
+    fn check_zeros(&self, s: &str) -> Result<(), OidParsingError> {
+        if self.leading_zeros {
+            return Ok(());
+        }
+        let bytes = s.as_bytes();
+        for pos in 0..bytes.len() {
+            let arc_start = pos == 0 || bytes[pos - 1] == b'.';
+            if arc_start && bytes[pos] == b'0' && bytes.get(pos + 1).is_some_and(u8::is_ascii_digit) {
+                return Err(OidParsingError::LeadingZero(pos));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error of [AbsoluteOid::parse_into] and [RelativeOid::parse_into]
+#[derive(Debug, Clone)]
+pub enum ParseIntoError {
+    Parsing(OidParsingError),
+    BufferTooSmall(BufferTooSmallError),
+}
+
+impl fmt::Display for ParseIntoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseIntoError::Parsing(_) => f.write_str("invalid oid text"),
+            ParseIntoError::BufferTooSmall(_) => f.write_str("oid doesn't fit into the buffer"),
+        }
+    }
+}
+
+impl crate::error::Error for ParseIntoError {
+    fn source(&self) -> Option<&(dyn crate::error::Error + 'static)> {
+        match self {
+            ParseIntoError::Parsing(error) => Some(error),
+            ParseIntoError::BufferTooSmall(error) => Some(error),
+        }
+    }
+}
+
+impl From<OidParsingError> for ParseIntoError {
+    fn from(error: OidParsingError) -> Self {
+        ParseIntoError::Parsing(error)
+    }
+}
+
+impl From<BufferTooSmallError> for ParseIntoError {
+    fn from(error: BufferTooSmallError) -> Self {
+        ParseIntoError::BufferTooSmall(error)
+    }
+}
+
+impl AbsoluteOid {
+    /// Number of bytes [AbsoluteOid::parse_into] needs for `s`
+    pub fn parsed_len(s: &str) -> Result<usize, OidParsingError> {
+        let (root, arcs) = ParseOptions::new().parse_absolute(s)?;
+        arcs_len(arcs).map(|len| root.as_bytes().len() + len)
+    }
+
+    /// Parses `s` into `buf` without allocating, accepts the same text as `FromStr` of
+    /// [AbsoluteOidVec](crate::AbsoluteOidVec)
+    /// ```
+    /// # use oid_str::{AbsoluteOid, ParseIntoError};
+    /// let mut buf = [0u8; 16];
+    /// let oid = AbsoluteOid::parse_into("1.2.840.113549", &mut buf).unwrap();
+    /// assert_eq!(oid.as_bytes(), b"\x2a\x86\x48\x86\xf7\x0d");
+    ///
+    /// let mut small = [0u8; 4];
+    /// let error = AbsoluteOid::parse_into("1.2.840.113549", &mut small).err().unwrap();
+    /// assert!(matches!(error, ParseIntoError::BufferTooSmall(e) if e.required == 6));
+    /// ```
+    pub fn parse_into<'a>(s: &str, buf: &'a mut [u8]) -> Result<&'a mut AbsoluteOid, ParseIntoError> {
+        let len = AbsoluteOid::parsed_len(s)?;
+        let (root, arcs) = ParseOptions::new().parse_absolute(s)?;
+        let bytes = buf.get_mut(..len).ok_or(BufferTooSmallError { required: len })?;
+        let root_len = root.as_bytes().len();
+        bytes[..root_len].copy_from_slice(root.as_bytes());
+        write_arcs(arcs, &mut bytes[root_len..]);
+        // SAFETY: bytes are the encoded root followed by encoded arcs
+        Ok(unsafe { AbsoluteOid::from_mut_bytes_unchecked(bytes) })
+    }
+}
+
+impl RelativeOid {
+    /// Number of bytes [RelativeOid::parse_into] needs for `s`
+    pub fn parsed_len(s: &str) -> Result<usize, OidParsingError> {
+        arcs_len(ParseOptions::new().parse_relative(s)?)
+    }
+
+    /// Parses `s` into `buf` without allocating, accepts the same text as `FromStr` of
+    /// [RelativeOidVec](crate::RelativeOidVec)
+    pub fn parse_into<'a>(s: &str, buf: &'a mut [u8]) -> Result<&'a mut RelativeOid, ParseIntoError> {
+        let len = RelativeOid::parsed_len(s)?;
+        let arcs = ParseOptions::new().parse_relative(s)?;
+        let bytes = buf.get_mut(..len).ok_or(BufferTooSmallError { required: len })?;
+        write_arcs(arcs, bytes);
+        // SAFETY: bytes are encoded arcs
+        Ok(unsafe { RelativeOid::from_mut_bytes_unchecked(bytes) })
+    }
+}
+
+/// Strict parsing, see [ParseOptions]
+impl<const N: usize> core::str::FromStr for AbsoluteOidArray<N> {
+    type Err = ParseIntoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AbsoluteOidArray::parse(s)
+    }
+}
+
+/// Strict parsing, see [ParseOptions]
+impl<const N: usize> core::str::FromStr for RelativeOidArray<N> {
+    type Err = ParseIntoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RelativeOidArray::parse(s)
+    }
+}
+
+impl<const N: usize> fmt::Display for AbsoluteOidArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_oid(), f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for AbsoluteOidArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_oid(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for RelativeOidArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_oid(), f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for RelativeOidArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_oid(), f)
+    }
+}
+
+fn arcs_len(arcs: StrArcs<'_>) -> Result<usize, OidParsingError> {
+    let mut len = 0;
+    for arc in arcs {
+        len += b128_len(arc?);
+    }
+    Ok(len)
+}
+
+/// Writes arcs, that are already checked by [arcs_len], to the start of `buf`
+fn write_arcs(arcs: StrArcs<'_>, buf: &mut [u8]) {
+    let mut pos = 0;
+    for arc in arcs.flatten() {
+        let (encoded, start) = encode_b128(arc);
+        let encoded = &encoded[start..];
+        buf[pos..pos + encoded.len()].copy_from_slice(encoded);
+        pos += encoded.len();
+    }
+}
+
 fn strip_leading_dot(s: &str) -> &str {
-    if s.as_bytes().get(0) == Some(&b'.') {
+    if s.as_bytes().first() == Some(&b'.') {
         &s[1..]
     } else {
         s
@@ -137,31 +582,60 @@ fn strip_leading_dot(s: &str) -> &str {
 mod owned {
     use core::{fmt, ops::Deref, str::FromStr};
 
-    use crate::{AbsoluteOidVec, RelativeOidVec};
+    use crate::{AbsoluteOidVec, RelativeOidVec, SmallAbsoluteOid, SmallRelativeOid};
 
-    use super::{parse_absolute, parse_relative, OidParsingError};
+    use super::{parse_urn, OidParsingError, ParseOptions, StrArcs};
+    use crate::RootOid;
 
+    /// Strict parsing, see [ParseOptions]
     impl FromStr for AbsoluteOidVec {
         type Err = OidParsingError;
 
         fn from_str(s: &str) -> Result<Self, Self::Err> {
-            let (root, arcs) = parse_absolute(s)?;
-            let mut vec = AbsoluteOidVec::from_root(root);
-            for arc in arcs {
-                vec.push(arc?);
-            }
+            AbsoluteOidVec::parse_with(s, &ParseOptions::new())
+        }
+    }
 
-            Ok(vec)
+    impl AbsoluteOidVec {
+        pub fn parse_with(s: &str, options: &ParseOptions) -> Result<AbsoluteOidVec, OidParsingError> {
+            from_arcs(options.parse_absolute(s)?)
+        }
+
+
+        /// Parses `urn:oid:` URN, see [parse_urn]
+        /// ```
+        /// # use oid_str::AbsoluteOidVec;
+        /// let oid = AbsoluteOidVec::from_urn("urn:oid:2.16.840.1.113883").unwrap();
+        /// assert_eq!(oid.to_string(), "2.16.840.1.113883");
+        /// assert_eq!(oid.to_urn(), "urn:oid:2.16.840.1.113883");
+        /// ```
+        pub fn from_urn(s: &str) -> Result<AbsoluteOidVec, OidParsingError> {
+            from_arcs(parse_urn(s)?)
         }
     }
 
+    fn from_arcs((root, arcs): (RootOid, StrArcs<'_>)) -> Result<AbsoluteOidVec, OidParsingError> {
+        let mut vec = AbsoluteOidVec::from_root(root);
+        for arc in arcs {
+            vec.push(arc?);
+        }
+
+        Ok(vec)
+    }
+
+    /// Strict parsing, see [ParseOptions]
     impl FromStr for RelativeOidVec {
         type Err = OidParsingError;
 
         fn from_str(s: &str) -> Result<Self, Self::Err> {
-            let arcs = parse_relative(s)?;
+            RelativeOidVec::parse_with(s, &ParseOptions::new())
+        }
+    }
+
+    impl RelativeOidVec {
+        pub fn parse_with(s: &str, options: &ParseOptions) -> Result<RelativeOidVec, OidParsingError> {
             let mut vec = RelativeOidVec::default();
-            for arc in arcs {
+            for arc in options.parse_relative(s)? {
                 vec.push(arc?);
             }
 
@@ -193,4 +667,83 @@ mod owned {
             fmt::Debug::fmt(self.deref(), f)
         }
     }
+
+    /// Strict parsing, see [ParseOptions]
+    impl FromStr for SmallAbsoluteOid {
+        type Err = OidParsingError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            SmallAbsoluteOid::parse_with(s, &ParseOptions::new())
+        }
+    }
+
+    impl SmallAbsoluteOid {
+        pub fn parse_with(s: &str, options: &ParseOptions) -> Result<SmallAbsoluteOid, OidParsingError> {
+            small_from_arcs(options.parse_absolute(s)?)
+        }
+
+        /// Parses `urn:oid:` URN, see [parse_urn]
+        /// ```
+        /// # use oid_str::SmallAbsoluteOid;
+        /// let oid = SmallAbsoluteOid::from_urn("urn:oid:2.16.840.1.113883").unwrap();
+        /// assert_eq!(oid.to_string(), "2.16.840.1.113883");
+        /// assert!(oid.is_inline());
+        /// ```
+        pub fn from_urn(s: &str) -> Result<SmallAbsoluteOid, OidParsingError> {
+            small_from_arcs(parse_urn(s)?)
+        }
+    }
+
+    fn small_from_arcs((root, arcs): (RootOid, StrArcs<'_>)) -> Result<SmallAbsoluteOid, OidParsingError> {
+        let mut oid = SmallAbsoluteOid::from_root(root);
+        for arc in arcs {
+            oid.push(arc?);
+        }
+
+        Ok(oid)
+    }
+
+    /// Strict parsing, see [ParseOptions]
+    impl FromStr for SmallRelativeOid {
+        type Err = OidParsingError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            SmallRelativeOid::parse_with(s, &ParseOptions::new())
+        }
+    }
+
+    impl SmallRelativeOid {
+        pub fn parse_with(s: &str, options: &ParseOptions) -> Result<SmallRelativeOid, OidParsingError> {
+            let mut oid = SmallRelativeOid::default();
+            for arc in options.parse_relative(s)? {
+                oid.push(arc?);
+            }
+
+            Ok(oid)
+        }
+    }
+
+    impl fmt::Display for SmallAbsoluteOid {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Display::fmt(self.deref(), f)
+        }
+    }
+
+    impl fmt::Debug for SmallAbsoluteOid {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Debug::fmt(self.deref(), f)
+        }
+    }
+
+    impl fmt::Display for SmallRelativeOid {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Display::fmt(self.deref(), f)
+        }
+    }
+
+    impl fmt::Debug for SmallRelativeOid {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Debug::fmt(self.deref(), f)
+        }
+    }
 }
@@ -0,0 +1,372 @@
+//! Tag-length-value encoding of oids, see X.690
+//!
+//! Only primitive encoding is supported, which is the only one allowed for oids.
+//! Implicit tagging is supported by passing a different [Tag],
+//! explicit tagging requires an outer constructed TLV and is left to the caller.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{encode::encode_b128, AbsoluteOid, Arc, IriError, OidDecodingError, RelativeOid, ARC_LEN};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TagClass {
+    Universal = 0b00,
+    Application = 0b01,
+    ContextSpecific = 0b10,
+    Private = 0b11,
+}
+
+/// Identifier of a primitive TLV
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tag {
+    pub class: TagClass,
+    pub number: u32,
+}
+
+impl Tag {
+    /// `OBJECT IDENTIFIER`
+    pub const OBJECT_IDENTIFIER: Tag = Tag::universal(6);
+    /// `RELATIVE-OID`
+    pub const RELATIVE_OID: Tag = Tag::universal(13);
+    /// `OID-IRI`
+    pub const OID_IRI: Tag = Tag::universal(35);
+    /// `RELATIVE-OID-IRI`
+    pub const RELATIVE_OID_IRI: Tag = Tag::universal(36);
+
+    pub const fn new(class: TagClass, number: u32) -> Tag {
+        Tag { class, number }
+    }
+
+    pub const fn universal(number: u32) -> Tag {
+        Tag::new(TagClass::Universal, number)
+    }
+
+    pub const fn application(number: u32) -> Tag {
+        Tag::new(TagClass::Application, number)
+    }
+
+    /// Tag of implicitly tagged fields, e.g. `[0] IMPLICIT OBJECT IDENTIFIER`
+    pub const fn context_specific(number: u32) -> Tag {
+        Tag::new(TagClass::ContextSpecific, number)
+    }
+
+    pub const fn private(number: u32) -> Tag {
+        Tag::new(TagClass::Private, number)
+    }
+
+    /// Identifier octets, returns the buffer and the index of the first byte
+    fn encode(&self) -> ([u8; ARC_LEN + 1], usize) {
+        let class = (self.class as u8) << 6;
+        let mut buf = [0u8; ARC_LEN + 1];
+        if self.number < 0x1f {
+            let k = buf.len() - 1;
+            buf[k] = class | self.number as u8;
+            return (buf, k);
+        }
+        // `Arc` is at least as wide as `u32`
+        let (number, start) = encode_b128(self.number as Arc);
+        buf[1..].copy_from_slice(&number);
+        buf[start] = class | 0x1f;
+        (buf, start)
+    }
+}
+
+/// Which length encodings are accepted by decoder
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodingRules {
+    /// Length may use long form with redundant leading zeros
+    Ber,
+    /// Length must use the shortest possible form
+    Der,
+}
+
+#[derive(Debug, Clone)]
+pub enum TlvError {
+    /// Input ended before the end of tag, length or value
+    Truncated,
+    /// Identifier octets do not match the expected [Tag]
+    UnexpectedTag,
+    /// Indefinite length is not allowed for primitive encoding
+    IndefiniteLength,
+    /// Length does not fit into `usize` or uses reserved `0xff` form
+    InvalidLength,
+    /// Length is not in the shortest possible form, as required by [EncodingRules::Der]
+    NonMinimalLength,
+    /// Value is not a valid oid
+    Content(OidDecodingError),
+    /// Value is not a valid OID-IRI or RELATIVE-OID-IRI
+    IriContent(IriError),
+}
+
+impl fmt::Display for TlvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TlvError::Truncated => f.write_str("truncated TLV"),
+            TlvError::UnexpectedTag => f.write_str("unexpected tag"),
+            TlvError::IndefiniteLength => f.write_str("indefinite length is not allowed"),
+            TlvError::InvalidLength => f.write_str("invalid length"),
+            TlvError::NonMinimalLength => f.write_str("length is not minimally encoded"),
+            TlvError::Content(_) => f.write_str("invalid oid content"),
+            TlvError::IriContent(_) => f.write_str("invalid OID-IRI content"),
+        }
+    }
+}
+
+impl crate::error::Error for TlvError {
+    fn source(&self) -> Option<&(dyn crate::error::Error + 'static)> {
+        match self {
+            TlvError::Content(error) => Some(error),
+            TlvError::IriContent(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<OidDecodingError> for TlvError {
+    fn from(error: OidDecodingError) -> Self {
+        TlvError::Content(error)
+    }
+}
+
+impl From<IriError> for TlvError {
+    fn from(error: IriError) -> Self {
+        TlvError::IriContent(error)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BufferTooSmallError {
+    /// Number of bytes needed to fit the whole output
+    pub required: usize,
+}
+
+impl fmt::Display for BufferTooSmallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "buffer is too small, {} bytes required", self.required)
+    }
+}
+
+impl crate::error::Error for BufferTooSmallError {}
+
+/// Splits TLV into value and the rest of the input
+pub(crate) fn decode_tlv(bytes: &[u8], tag: Tag, rules: EncodingRules) -> Result<(&[u8], &[u8]), TlvError> {
+    let (tag_buf, tag_start) = tag.encode();
+    let tag_bytes = &tag_buf[tag_start..];
+    if bytes.len() < tag_bytes.len() {
+        return Err(TlvError::Truncated);
+    }
+    let (found_tag, bytes) = bytes.split_at(tag_bytes.len());
+    if found_tag != tag_bytes {
+        return Err(TlvError::UnexpectedTag);
+    }
+
+    let (&first, mut bytes) = bytes.split_first().ok_or(TlvError::Truncated)?;
+    let len = match first {
+        0..=0x7f => first as usize,
+        0x80 => return Err(TlvError::IndefiniteLength),
+        0xff => return Err(TlvError::InvalidLength),
+        _ => {
+            let n_bytes = (first & 0x7f) as usize;
+            if bytes.len() < n_bytes {
+                return Err(TlvError::Truncated);
+            }
+            let (len_bytes, rest) = bytes.split_at(n_bytes);
+            bytes = rest;
+
+            let mut len: usize = 0;
+            for &byte in len_bytes {
+                if len > usize::MAX >> 8 {
+                    return Err(TlvError::InvalidLength);
+                }
+                len = (len << 8) | byte as usize;
+            }
+            // DER requires short form whenever possible and no leading zero bytes
+            if rules == EncodingRules::Der && (len < 0x80 || len_bytes[0] == 0) {
+                return Err(TlvError::NonMinimalLength);
+            }
+            len
+        }
+    };
+
+    if bytes.len() < len {
+        return Err(TlvError::Truncated);
+    }
+    Ok(bytes.split_at(len))
+}
+
+/// Length octets, returns the buffer and the index of the first byte
+fn encode_len(len: usize) -> ([u8; LEN_BUF], usize) {
+    let mut buf = [0u8; LEN_BUF];
+    let mut k = LEN_BUF - 1;
+    if len < 0x80 {
+        buf[k] = len as u8;
+        return (buf, k);
+    }
+    let mut rest = len;
+    while rest > 0 {
+        buf[k] = rest as u8;
+        rest >>= 8;
+        k -= 1;
+    }
+    buf[k] = 0x80 | (LEN_BUF - 1 - k) as u8;
+    (buf, k)
+}
+
+const LEN_BUF: usize = core::mem::size_of::<usize>() + 1;
+
+pub(crate) fn tlv_len(tag: Tag, content: &[u8]) -> usize {
+    let (_, tag_start) = tag.encode();
+    let (_, len_start) = encode_len(content.len());
+    (ARC_LEN + 1 - tag_start) + (LEN_BUF - len_start) + content.len()
+}
+
+pub(crate) fn write_tlv(tag: Tag, content: &[u8], buf: &mut [u8]) -> Result<usize, BufferTooSmallError> {
+    let required = tlv_len(tag, content);
+    if buf.len() < required {
+        return Err(BufferTooSmallError { required });
+    }
+    let (tag_buf, tag_start) = tag.encode();
+    let (len_buf, len_start) = encode_len(content.len());
+    let parts = [&tag_buf[tag_start..], &len_buf[len_start..], content];
+
+    let mut pos = 0;
+    for part in parts.iter() {
+        buf[pos..pos + part.len()].copy_from_slice(part);
+        pos += part.len();
+    }
+    debug_assert_eq!(pos, required);
+    Ok(pos)
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn to_tlv(tag: Tag, content: &[u8]) -> Vec<u8> {
+    let mut buf = alloc::vec![0; tlv_len(tag, content)];
+    write_tlv(tag, content, &mut buf).unwrap();
+    buf
+}
+
+impl AbsoluteOid {
+    /// Decode `OBJECT IDENTIFIER` TLV, returns the oid and the rest of the input
+    ///
+    /// # Examples
+    /// ```
+    /// # use oid_str::AbsoluteOid;
+    ///
+    /// let (oid, rest) = AbsoluteOid::from_der_tlv(b"\x06\x03\x2b\x06\x01\x05").unwrap();
+    /// assert_eq!(oid.to_string(), "1.3.6.1");
+    /// assert_eq!(rest, b"\x05");
+    /// ```
+    pub fn from_der_tlv(bytes: &[u8]) -> Result<(&AbsoluteOid, &[u8]), TlvError> {
+        AbsoluteOid::from_tlv(bytes, Tag::OBJECT_IDENTIFIER, EncodingRules::Der)
+    }
+
+    /// Same as [AbsoluteOid::from_der_tlv], but accepts non-minimal length encoding
+    pub fn from_ber_tlv(bytes: &[u8]) -> Result<(&AbsoluteOid, &[u8]), TlvError> {
+        AbsoluteOid::from_tlv(bytes, Tag::OBJECT_IDENTIFIER, EncodingRules::Ber)
+    }
+
+    /// Decode TLV with an arbitrary tag, e.g. implicitly tagged one
+    pub fn from_tlv(
+        bytes: &[u8],
+        tag: Tag,
+        rules: EncodingRules,
+    ) -> Result<(&AbsoluteOid, &[u8]), TlvError> {
+        let (value, rest) = decode_tlv(bytes, tag, rules)?;
+        Ok((AbsoluteOid::from_bytes(value)?, rest))
+    }
+
+    /// Length of `OBJECT IDENTIFIER` TLV in bytes
+    pub fn der_tlv_len(&self) -> usize {
+        self.tlv_len(Tag::OBJECT_IDENTIFIER)
+    }
+
+    /// Length of TLV with the given tag in bytes
+    pub fn tlv_len(&self, tag: Tag) -> usize {
+        tlv_len(tag, self.as_bytes())
+    }
+
+    /// Write `OBJECT IDENTIFIER` TLV into `buf`, returns the number of bytes written
+    ///
+    /// # Examples
+    /// ```
+    /// # use oid_str::AbsoluteOid;
+    ///
+    /// let oid = AbsoluteOid::from_bytes(b"\x2b\x06\x01").unwrap();
+    /// let mut buf = [0; 16];
+    /// let len = oid.write_der_tlv(&mut buf).unwrap();
+    /// assert_eq!(&buf[..len], b"\x06\x03\x2b\x06\x01");
+    /// ```
+    pub fn write_der_tlv(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmallError> {
+        self.write_tlv(Tag::OBJECT_IDENTIFIER, buf)
+    }
+
+    /// Write TLV with the given tag into `buf`, returns the number of bytes written
+    pub fn write_tlv(&self, tag: Tag, buf: &mut [u8]) -> Result<usize, BufferTooSmallError> {
+        write_tlv(tag, self.as_bytes(), buf)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_der_tlv(&self) -> Vec<u8> {
+        self.to_tlv(Tag::OBJECT_IDENTIFIER)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_tlv(&self, tag: Tag) -> Vec<u8> {
+        to_tlv(tag, self.as_bytes())
+    }
+}
+
+impl RelativeOid {
+    /// Decode `RELATIVE-OID` TLV, returns the oid and the rest of the input
+    pub fn from_der_tlv(bytes: &[u8]) -> Result<(&RelativeOid, &[u8]), TlvError> {
+        RelativeOid::from_tlv(bytes, Tag::RELATIVE_OID, EncodingRules::Der)
+    }
+
+    /// Same as [RelativeOid::from_der_tlv], but accepts non-minimal length encoding
+    pub fn from_ber_tlv(bytes: &[u8]) -> Result<(&RelativeOid, &[u8]), TlvError> {
+        RelativeOid::from_tlv(bytes, Tag::RELATIVE_OID, EncodingRules::Ber)
+    }
+
+    /// Decode TLV with an arbitrary tag, e.g. implicitly tagged one
+    pub fn from_tlv(
+        bytes: &[u8],
+        tag: Tag,
+        rules: EncodingRules,
+    ) -> Result<(&RelativeOid, &[u8]), TlvError> {
+        let (value, rest) = decode_tlv(bytes, tag, rules)?;
+        let oid = RelativeOid::from_bytes(value).map_err(OidDecodingError::from)?;
+        Ok((oid, rest))
+    }
+
+    /// Length of `RELATIVE-OID` TLV in bytes
+    pub fn der_tlv_len(&self) -> usize {
+        self.tlv_len(Tag::RELATIVE_OID)
+    }
+
+    /// Length of TLV with the given tag in bytes
+    pub fn tlv_len(&self, tag: Tag) -> usize {
+        tlv_len(tag, self.as_bytes())
+    }
+
+    /// Write `RELATIVE-OID` TLV into `buf`, returns the number of bytes written
+    pub fn write_der_tlv(&self, buf: &mut [u8]) -> Result<usize, BufferTooSmallError> {
+        self.write_tlv(Tag::RELATIVE_OID, buf)
+    }
+
+    /// Write TLV with the given tag into `buf`, returns the number of bytes written
+    pub fn write_tlv(&self, tag: Tag, buf: &mut [u8]) -> Result<usize, BufferTooSmallError> {
+        write_tlv(tag, self.as_bytes(), buf)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_der_tlv(&self) -> Vec<u8> {
+        self.to_tlv(Tag::RELATIVE_OID)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn to_tlv(&self, tag: Tag) -> Vec<u8> {
+        to_tlv(tag, self.as_bytes())
+    }
+}
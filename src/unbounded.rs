@@ -0,0 +1,559 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::fmt;
+
+use crate::{
+    borrowed::{next_b128_error, next_unbounded_b128_error, root_len, to_position},
+    AbsoluteOid, Arc, B128Error, OidDecodingError, RelativeOid, RootOid,
+};
+
+#[derive(PartialEq, Eq, Hash)]
+#[repr(transparent)]
+// DST implementation is the same as `RelativeOid`
+/// Same as [RelativeOid], but arcs are not limited by [Arc]
+///
+/// Each arc is a well formed base-128 number of any length,
+/// see [BigArc].
+///
+/// # Invariants
+/// Last arc must be finished, that is, last byte has high bit **not** set.
+///
+/// Leading zero bits must be discarded.
+/// Byte `0x80` is disallowed at the start of an arc.
+pub struct UnboundedRelativeOid([u8]);
+
+impl UnboundedRelativeOid {
+    /// Cast UnboundedRelativeOid from bytes
+    ///
+    /// # Safety
+    /// byte sequence must ensure invariants of UnboundedRelativeOid are satisfied
+    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &UnboundedRelativeOid {
+        // SAFETY: Casting to UnboundedRelativeOid is safe because its internal representation
+        // is a [u8] too and it is repr(transparent).
+        // Making a reference is then safe because its lifetime
+        // is bound by the lifetime of the given `bytes`.
+        &*(bytes as *const [u8] as *const UnboundedRelativeOid)
+    }
+
+    /// Bytes of BER-encoded oid
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub(crate) fn check_bytes(bytes: &[u8]) -> Result<(), B128Error> {
+        if let Some(error) = next_unbounded_b128_error(bytes) {
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<&UnboundedRelativeOid, B128Error> {
+        UnboundedRelativeOid::check_bytes(bytes)?;
+        // SAFETY: check above confirms that bytes are a sequence of finished arcs
+        Ok(unsafe { UnboundedRelativeOid::from_bytes_unchecked(bytes) })
+    }
+
+    /// Creates a reference to empty oid
+    pub fn empty() -> &'static UnboundedRelativeOid {
+        RelativeOid::empty().as_unbounded()
+    }
+
+    /// Convert to [RelativeOid], if every arc fits into [Arc]
+    pub fn as_bounded(&self) -> Result<&RelativeOid, B128Error> {
+        RelativeOid::from_bytes(self.as_bytes())
+    }
+
+    pub fn arcs(&self) -> BigArcs<'_> {
+        BigArcs {
+            bytes: self.as_bytes(),
+        }
+    }
+}
+
+impl RelativeOid {
+    /// Every [RelativeOid] is also a valid [UnboundedRelativeOid]
+    pub fn as_unbounded(&self) -> &UnboundedRelativeOid {
+        // SAFETY: invariants of `RelativeOid` are stricter than invariants of `UnboundedRelativeOid`
+        unsafe { UnboundedRelativeOid::from_bytes_unchecked(self.as_bytes()) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&UnboundedRelativeOid> for Box<UnboundedRelativeOid> {
+    fn from(s: &UnboundedRelativeOid) -> Box<UnboundedRelativeOid> {
+        let boxed: Box<[u8]> = Box::from(s.as_bytes());
+        // SAFETY: Casting to UnboundedRelativeOid is safe because its internal representation
+        // is a [u8] too and it is repr(transparent)
+        // [u8] comes from UnboundedRelativeOid so it's invariants are satisfied
+        unsafe { Box::from_raw(Box::into_raw(boxed) as *mut UnboundedRelativeOid) }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash)]
+#[repr(transparent)]
+// DST implementation is the same as `RelativeOid`
+/// Same as [AbsoluteOid], but arcs after the root are not limited by [Arc]
+///
+/// Note, the root subidentifier still has to fit into [Arc], see [RootOid].
+///
+/// # Invariants
+/// The first arc must conform to [AbsoluteOid] invariants.
+///
+/// The rest of the bytes must conform to [UnboundedRelativeOid] invariants
+pub struct UnboundedAbsoluteOid([u8]);
+
+impl UnboundedAbsoluteOid {
+    /// Cast UnboundedAbsoluteOid from bytes
+    ///
+    /// # Safety
+    /// byte sequence must ensure invariants of UnboundedAbsoluteOid are satisfied
+    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &UnboundedAbsoluteOid {
+        // SAFETY: Casting to UnboundedAbsoluteOid is safe because its internal representation
+        // is a [u8] too and it is repr(transparent).
+        // Making a reference is then safe because its lifetime
+        // is bound by the lifetime of the given `bytes`.
+        &*(bytes as *const [u8] as *const UnboundedAbsoluteOid)
+    }
+
+    /// Bytes of BER-encoded oid
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub(crate) fn check_bytes(bytes: &[u8]) -> Result<(), OidDecodingError> {
+        if bytes.is_empty() {
+            return Err(OidDecodingError::Empty);
+        }
+        // if there is no terminating byte, the root is unfinished
+        let root_len = root_len(bytes).unwrap_or(bytes.len());
+        AbsoluteOid::check_bytes(&bytes[..root_len])?;
+        if let Some(mut error) = next_unbounded_b128_error(&bytes[root_len..]) {
+            error.pos = error.pos.saturating_add(to_position(root_len));
+            return Err(OidDecodingError::Base128(error));
+        }
+        Ok(())
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<&UnboundedAbsoluteOid, OidDecodingError> {
+        UnboundedAbsoluteOid::check_bytes(bytes)?;
+        // SAFETY: check above confirms that bytes contain a valid root and a sequence of finished arcs
+        Ok(unsafe { UnboundedAbsoluteOid::from_bytes_unchecked(bytes) })
+    }
+
+    /// Convert to [AbsoluteOid], if every arc fits into [Arc]
+    pub fn as_bounded(&self) -> Result<&AbsoluteOid, OidDecodingError> {
+        AbsoluteOid::from_bytes(self.as_bytes())
+    }
+
+    /// First two arcs
+    pub fn root(&self) -> RootOid {
+        // SAFETY: the root of `UnboundedAbsoluteOid` is a valid `AbsoluteOid`
+        let root = unsafe { AbsoluteOid::from_bytes_unchecked(&self.as_bytes()[..self.root_len()]) };
+        root.root()
+    }
+
+    fn root_len(&self) -> usize {
+        // `UnboundedAbsoluteOid` always contains a finished root arc
+        root_len(self.as_bytes()).unwrap()
+    }
+
+    /// All arcs except the first two(the [RootOid])
+    pub fn tail(&self) -> &UnboundedRelativeOid {
+        let root_len = self.root_len();
+        // SAFETY: the root is a single finished arc, so the rest is a valid `UnboundedRelativeOid`
+        unsafe { UnboundedRelativeOid::from_bytes_unchecked(&self.as_bytes()[root_len..]) }
+    }
+}
+
+impl AbsoluteOid {
+    /// Every [AbsoluteOid] is also a valid [UnboundedAbsoluteOid]
+    pub fn as_unbounded(&self) -> &UnboundedAbsoluteOid {
+        // SAFETY: invariants of `AbsoluteOid` are stricter than invariants of `UnboundedAbsoluteOid`
+        unsafe { UnboundedAbsoluteOid::from_bytes_unchecked(self.as_bytes()) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&UnboundedAbsoluteOid> for Box<UnboundedAbsoluteOid> {
+    fn from(s: &UnboundedAbsoluteOid) -> Box<UnboundedAbsoluteOid> {
+        let boxed: Box<[u8]> = Box::from(s.as_bytes());
+        // SAFETY: Casting to UnboundedAbsoluteOid is safe because its internal representation
+        // is a [u8] too and it is repr(transparent)
+        // [u8] comes from UnboundedAbsoluteOid so it's invariants are satisfied
+        unsafe { Box::from_raw(Box::into_raw(boxed) as *mut UnboundedAbsoluteOid) }
+    }
+}
+
+/// A single arc of any size
+///
+/// Borrows raw base-128 bytes of the arc.
+/// It is printed in decimal, same as [Arc].
+/// Without `alloc`, printing arcs longer than 128 bytes fails.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BigArc<'a> {
+    /// # Invariants
+    /// Exactly one finished arc without leading `0x80` byte
+    bytes: &'a [u8],
+}
+
+impl<'a> BigArc<'a> {
+    /// Base-128 encoded arc
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Arc value, if it fits into [Arc]
+    pub fn to_arc(&self) -> Option<Arc> {
+        if next_b128_error(self.bytes).is_some() {
+            return None;
+        }
+        // SAFETY: just checked the arc fits into `Arc`
+        let oid = unsafe { RelativeOid::from_bytes_unchecked(self.bytes) };
+        oid.arcs().next()
+    }
+}
+
+pub struct BigArcs<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> core::iter::Iterator for BigArcs<'a> {
+    type Item = BigArc<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the last byte always finishes an arc
+        let len = self.bytes.iter().position(|&byte| byte & 0x80 == 0)? + 1;
+        let (bytes, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Some(BigArc { bytes })
+    }
+}
+
+/// Longest arc in bytes, that is printed without allocating
+const STACK_ARC_LEN: usize = 128;
+
+impl fmt::Display for BigArc<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(arc) = self.to_arc() {
+            return fmt::Display::fmt(&arc, f);
+        }
+        if self.bytes.len() <= STACK_ARC_LEN {
+            let mut words = [0; decimal_words(STACK_ARC_LEN)];
+            let mut groups = [0; decimal_groups(STACK_ARC_LEN)];
+            return write_decimal(f, self.bytes, &mut words, &mut groups);
+        }
+        #[cfg(feature = "alloc")]
+        {
+            let mut words = alloc::vec![0; decimal_words(self.bytes.len())];
+            let mut groups = alloc::vec![0; decimal_groups(self.bytes.len())];
+            write_decimal(f, self.bytes, &mut words, &mut groups)
+        }
+        #[cfg(not(feature = "alloc"))]
+        Err(fmt::Error)
+    }
+}
+
+/// Number of 32-bit words, that hold an arc of `len` base-128 bytes
+const fn decimal_words(len: usize) -> usize {
+    (len * 7).div_ceil(32)
+}
+
+/// Upper bound of base 10^9 digits of an arc of `len` base-128 bytes, 10^9 > 2^29
+const fn decimal_groups(len: usize) -> usize {
+    len * 7 / 29 + 1
+}
+
+/// Prints the arc by repeated division by 10^9, `words` and `groups` must be zeroed
+fn write_decimal(
+    f: &mut fmt::Formatter<'_>,
+    bytes: &[u8],
+    words: &mut [u32],
+    groups: &mut [u32],
+) -> fmt::Result {
+    const BASE: u64 = 1_000_000_000;
+
+    // little-endian base 2^32 value of the arc
+    for &byte in bytes {
+        let mut carry = (byte & 0x7f) as u32;
+        for word in words.iter_mut() {
+            let value = (*word as u64) << 7 | carry as u64;
+            *word = value as u32;
+            carry = (value >> 32) as u32;
+        }
+    }
+
+    // little-endian base 10^9 digits
+    let mut len = words.len();
+    let mut count = 0;
+    while len > 0 {
+        let mut remainder = 0;
+        for word in words[..len].iter_mut().rev() {
+            let value = remainder << 32 | *word as u64;
+            *word = (value / BASE) as u32;
+            remainder = value % BASE;
+        }
+        groups[count] = remainder as u32;
+        count += 1;
+        while len > 0 && words[len - 1] == 0 {
+            len -= 1;
+        }
+    }
+
+    // the value does not fit into `Arc`, so it is not zero
+    let mut groups = groups[..count].iter().rev();
+    write!(f, "{}", groups.next().unwrap())?;
+    for group in groups {
+        write!(f, "{:09}", group)?;
+    }
+    Ok(())
+}
+
+impl fmt::Debug for BigArc<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for UnboundedRelativeOid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for arc in self.arcs() {
+            write!(f, ".{}", arc)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for UnboundedRelativeOid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for UnboundedAbsoluteOid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.root(), self.tail())
+    }
+}
+
+impl fmt::Debug for UnboundedAbsoluteOid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod owned {
+    use alloc::{borrow::ToOwned, vec::Vec};
+    use core::{borrow::Borrow, fmt, ops::Deref, str::FromStr};
+
+    use crate::{
+        parse_absolute, parse_relative, str::StrArcs, AbsoluteOidVec, OidParsingError,
+        RelativeOidVec,
+    };
+
+    use super::*;
+
+    /// Owned version of [UnboundedRelativeOid]
+    #[derive(Clone, PartialEq, Eq, Hash)]
+    pub struct UnboundedRelativeOidVec {
+        bytes: Vec<u8>,
+    }
+
+    impl Default for UnboundedRelativeOidVec {
+        fn default() -> Self {
+            UnboundedRelativeOidVec::from_oid(UnboundedRelativeOid::empty())
+        }
+    }
+
+    impl Deref for UnboundedRelativeOidVec {
+        type Target = UnboundedRelativeOid;
+
+        fn deref(&self) -> &Self::Target {
+            self.as_oid()
+        }
+    }
+
+    impl UnboundedRelativeOidVec {
+        pub fn from_oid(oid: &UnboundedRelativeOid) -> UnboundedRelativeOidVec {
+            UnboundedRelativeOidVec {
+                bytes: oid.as_bytes().to_vec(),
+            }
+        }
+
+        pub fn into_bytes(self) -> Vec<u8> {
+            self.bytes
+        }
+
+        pub fn as_oid(&self) -> &UnboundedRelativeOid {
+            // SAFETY: we only store valid UnboundedRelativeOid bytes
+            unsafe { UnboundedRelativeOid::from_bytes_unchecked(self.bytes.as_slice()) }
+        }
+
+        pub fn extend(&mut self, oid: &UnboundedRelativeOid) {
+            self.bytes.extend(oid.as_bytes())
+        }
+    }
+
+    impl From<RelativeOidVec> for UnboundedRelativeOidVec {
+        fn from(oid: RelativeOidVec) -> Self {
+            UnboundedRelativeOidVec {
+                bytes: oid.into_bytes(),
+            }
+        }
+    }
+
+    /// Owned version of [UnboundedAbsoluteOid]
+    #[derive(Clone, PartialEq, Eq, Hash)]
+    pub struct UnboundedAbsoluteOidVec {
+        bytes: Vec<u8>,
+    }
+
+    impl Deref for UnboundedAbsoluteOidVec {
+        type Target = UnboundedAbsoluteOid;
+
+        fn deref(&self) -> &Self::Target {
+            self.as_oid()
+        }
+    }
+
+    impl UnboundedAbsoluteOidVec {
+        pub fn from_root(root: RootOid) -> Self {
+            UnboundedAbsoluteOidVec::from_oid(root.as_absolute().as_unbounded())
+        }
+
+        pub fn from_oid(oid: &UnboundedAbsoluteOid) -> UnboundedAbsoluteOidVec {
+            UnboundedAbsoluteOidVec {
+                bytes: oid.as_bytes().to_vec(),
+            }
+        }
+
+        pub fn into_bytes(self) -> Vec<u8> {
+            self.bytes
+        }
+
+        pub fn as_oid(&self) -> &UnboundedAbsoluteOid {
+            // SAFETY: we only store valid UnboundedAbsoluteOid bytes
+            unsafe { UnboundedAbsoluteOid::from_bytes_unchecked(self.bytes.as_slice()) }
+        }
+
+        pub fn extend(&mut self, oid: &UnboundedRelativeOid) {
+            self.bytes.extend(oid.as_bytes())
+        }
+    }
+
+    impl From<AbsoluteOidVec> for UnboundedAbsoluteOidVec {
+        fn from(oid: AbsoluteOidVec) -> Self {
+            UnboundedAbsoluteOidVec {
+                bytes: oid.into_bytes(),
+            }
+        }
+    }
+
+    impl Borrow<UnboundedRelativeOid> for UnboundedRelativeOidVec {
+        fn borrow(&self) -> &UnboundedRelativeOid {
+            self.as_oid()
+        }
+    }
+
+    impl ToOwned for UnboundedRelativeOid {
+        type Owned = UnboundedRelativeOidVec;
+
+        fn to_owned(&self) -> Self::Owned {
+            UnboundedRelativeOidVec::from_oid(self)
+        }
+    }
+
+    impl Borrow<UnboundedAbsoluteOid> for UnboundedAbsoluteOidVec {
+        fn borrow(&self) -> &UnboundedAbsoluteOid {
+            self.as_oid()
+        }
+    }
+
+    impl ToOwned for UnboundedAbsoluteOid {
+        type Owned = UnboundedAbsoluteOidVec;
+
+        fn to_owned(&self) -> Self::Owned {
+            UnboundedAbsoluteOidVec::from_oid(self)
+        }
+    }
+
+    impl FromStr for UnboundedRelativeOidVec {
+        type Err = OidParsingError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let arcs = parse_relative(s)?;
+            let mut bytes = Vec::new();
+            push_decimal_arcs(&mut bytes, arcs);
+            Ok(UnboundedRelativeOidVec { bytes })
+        }
+    }
+
+    impl FromStr for UnboundedAbsoluteOidVec {
+        type Err = OidParsingError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (root, arcs) = parse_absolute(s)?;
+            let mut bytes = root.as_bytes().to_vec();
+            push_decimal_arcs(&mut bytes, arcs);
+            Ok(UnboundedAbsoluteOidVec { bytes })
+        }
+    }
+
+    impl fmt::Display for UnboundedRelativeOidVec {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Display::fmt(self.deref(), f)
+        }
+    }
+
+    impl fmt::Debug for UnboundedRelativeOidVec {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Debug::fmt(self.deref(), f)
+        }
+    }
+
+    impl fmt::Display for UnboundedAbsoluteOidVec {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Display::fmt(self.deref(), f)
+        }
+    }
+
+    impl fmt::Debug for UnboundedAbsoluteOidVec {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Debug::fmt(self.deref(), f)
+        }
+    }
+
+    /// Encodes each arc without limiting its value
+    ///
+    /// `arcs` must be already checked to contain only decimal digits
+    fn push_decimal_arcs(bytes: &mut Vec<u8>, arcs: StrArcs<'_>) {
+        // little-endian base-128 digits of the current arc
+        let mut digits: Vec<u8> = Vec::new();
+        for part in arcs.into_parts() {
+            if part.is_empty() {
+                break;
+            }
+            digits.clear();
+            for c in part.bytes() {
+                let mut carry = (c - b'0') as u32;
+                for digit in digits.iter_mut() {
+                    let value = *digit as u32 * 10 + carry;
+                    *digit = (value & 0x7f) as u8;
+                    carry = value >> 7;
+                }
+                if carry > 0 {
+                    digits.push(carry as u8);
+                }
+            }
+            if digits.is_empty() {
+                digits.push(0);
+            }
+            let (last, rest) = digits.split_first().unwrap();
+            bytes.extend(rest.iter().rev().map(|digit| digit | 0x80));
+            bytes.push(*last);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub use owned::{UnboundedAbsoluteOidVec, UnboundedRelativeOidVec};
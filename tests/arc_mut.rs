@@ -0,0 +1,169 @@
+use oid_str::{AbsoluteOidVec, RelativeOid, RelativeOidVec};
+
+fn relative(s: &str) -> RelativeOidVec {
+    s.parse().unwrap()
+}
+
+fn absolute(s: &str) -> AbsoluteOidVec {
+    s.parse().unwrap()
+}
+
+#[test]
+fn pop_and_truncate() {
+    let mut oid = relative(".1.16384.2");
+    assert_eq!(oid.pop(), Some(2));
+    assert_eq!(oid.pop(), Some(16384));
+    assert_eq!(oid.pop(), Some(1));
+    assert_eq!(oid.pop(), None);
+
+    // multi-byte root and arcs
+    let mut oid = absolute("2.999.840.113549");
+    assert_eq!(oid.pop(), Some(113549));
+    assert_eq!(oid.pop(), Some(840));
+    assert_eq!(oid.pop(), None);
+    assert_eq!(oid.to_string(), "2.999");
+
+    let mut oid = relative(".1.200.3.4");
+    oid.truncate_arcs(10);
+    assert_eq!(oid.to_string(), ".1.200.3.4");
+    oid.truncate_arcs(2);
+    assert_eq!(oid.to_string(), ".1.200");
+    oid.truncate_arcs(0);
+    assert!(oid.is_empty());
+
+    let mut oid = absolute("2.999.1.2");
+    oid.truncate_arcs(3);
+    assert_eq!(oid.to_string(), "2.999.1");
+    oid.truncate_arcs(0);
+    assert_eq!(oid.to_string(), "2.999");
+}
+
+#[test]
+fn set_arc_reencodes() {
+    let mut oid = relative(".1.2.3");
+    assert_eq!(oid.set_arc(1, 16384), 2);
+    assert_eq!(oid.as_bytes(), b"\x01\x81\x80\x00\x03");
+    assert_eq!(oid.set_arc(1, 5), 16384);
+    assert_eq!(oid.as_bytes(), b"\x01\x05\x03");
+
+    let mut oid = absolute("1.2.840.113549");
+    assert_eq!(oid.set_arc(3, 1), 113549);
+    assert_eq!(oid.set_arc(2, 113549), 840);
+    assert_eq!(oid.to_string(), "1.2.113549.1");
+}
+
+#[test]
+fn insert_and_remove() {
+    let mut oid = relative(".1.3");
+    oid.insert_arc(1, 200);
+    oid.insert_arc(3, 4);
+    oid.insert_arc(0, 0);
+    assert_eq!(oid.to_string(), ".0.1.200.3.4");
+    assert_eq!(oid.remove_arc(2), 200);
+    assert_eq!(oid.remove_arc(3), 4);
+    assert_eq!(oid.to_string(), ".0.1.3");
+
+    let mut oid = absolute("1.3");
+    oid.insert_arc(2, 6);
+    oid.insert_arc(2, 5);
+    assert_eq!(oid.to_string(), "1.3.5.6");
+    assert_eq!(oid.remove_arc(3), 6);
+}
+
+#[test]
+#[should_panic(expected = "insertion index (is 3) should be <= number of arcs (is 2)")]
+fn insert_out_of_bounds() {
+    relative(".1.2").insert_arc(3, 0);
+}
+
+#[test]
+#[should_panic(expected = "arc index (is 4) should be < number of arcs (is 4)")]
+fn remove_out_of_bounds() {
+    absolute("1.3.6.1").remove_arc(4);
+}
+
+#[test]
+#[should_panic(expected = "arc index (is 1) should be >= 2, the root can't be edited")]
+fn set_root_arc() {
+    absolute("1.3.6.1").set_arc(1, 2);
+}
+
+#[test]
+#[should_panic(expected = "arc index (is 0) should be >= 2, the root can't be edited")]
+fn replace_range_with_root() {
+    absolute("1.3.6.1").replace_range(..3, RelativeOid::empty());
+}
+
+#[test]
+fn absolute_indices_match_arcs() {
+    let oid = absolute("2.999.840.113549.1");
+    for index in 2..oid.arcs().count() {
+        let expected = oid.arcs().nth(index).unwrap();
+
+        let mut edited = oid.clone();
+        assert_eq!(edited.set_arc(index, 7), expected);
+        assert_eq!(edited.arcs().nth(index), Some(7));
+
+        let mut edited = oid.clone();
+        assert_eq!(edited.remove_arc(index), expected);
+        assert_eq!(edited.arcs().count(), oid.arcs().count() - 1);
+
+        let mut edited = oid.clone();
+        edited.insert_arc(index, 7);
+        assert_eq!(edited.arcs().nth(index), Some(7));
+        assert_eq!(edited.arcs().nth(index + 1), Some(expected));
+
+        let mut edited = oid.clone();
+        edited.truncate_arcs(index);
+        assert_eq!(edited.arcs().count(), index);
+        assert!(edited.arcs().eq(oid.arcs().take(index)));
+
+        let mut edited = oid.clone();
+        edited.replace_range(index..=index, &relative(".7.8"));
+        assert_eq!(edited.arcs().nth(index), Some(7));
+        assert_eq!(edited.arcs().nth(index + 1), Some(8));
+    }
+}
+
+#[test]
+fn replace_range() {
+    let replacement = relative(".7.8.9");
+    let mut oid = relative(".1.2.3.4");
+    oid.replace_range(1..3, &replacement);
+    assert_eq!(oid.to_string(), ".1.7.8.9.4");
+    oid.replace_range(..=1, RelativeOid::empty());
+    assert_eq!(oid.to_string(), ".8.9.4");
+    oid.replace_range(3.., &replacement);
+    assert_eq!(oid.to_string(), ".8.9.4.7.8.9");
+    oid.replace_range(.., &relative(".128"));
+    assert_eq!(oid.as_bytes(), b"\x81\x00");
+
+    let mut oid = absolute("1.3.6.1.4.1");
+    oid.replace_range(2..4, &relative(".2.25"));
+    assert_eq!(oid.to_string(), "1.3.2.25.4.1");
+    oid.replace_range(4.., RelativeOid::empty());
+    assert_eq!(oid.to_string(), "1.3.2.25");
+}
+
+#[test]
+#[should_panic(expected = "arc range 1..4 is out of bounds of 3 arcs")]
+fn replace_range_out_of_bounds() {
+    relative(".1.2.3").replace_range(1..4, RelativeOid::empty());
+}
+
+#[test]
+fn retain_and_clear() {
+    let mut oid = relative(".1.300.2.70000.3");
+    oid.retain(|arc| arc > 100);
+    assert_eq!(oid.to_string(), ".300.70000");
+    oid.clear();
+    assert!(oid.is_empty());
+
+    let mut oid = absolute("2.5.4.3");
+    oid.retain(|arc| arc != 4);
+    assert_eq!(oid.to_string(), "2.5.3");
+    oid.clear();
+    assert_eq!(oid.to_string(), "2.5");
+    oid.push(29);
+    assert_eq!(oid.to_string(), "2.5.29");
+}
@@ -0,0 +1,68 @@
+#![cfg(any(feature = "arc-u64", feature = "arc-u128"))]
+
+use oid_str::{write_b128, Arc, RelativeOid, RelativeOidVec, ARC_LEN};
+
+#[test]
+fn decode_arc_above_u32() {
+    let oid = RelativeOid::from_bytes(b"\x90\x80\x80\x80\x00").unwrap();
+    let arcs: Vec<_> = oid.arcs().collect();
+    assert_eq!(arcs, [1 << 32]);
+    assert_eq!(oid.to_string(), ".4294967296");
+}
+
+#[test]
+fn parse_arc_above_u32() {
+    let oid: RelativeOidVec = "1.4294967296".parse().unwrap();
+    assert_eq!(oid.as_bytes(), b"\x01\x90\x80\x80\x80\x00");
+}
+
+#[test]
+fn encode_max_arc() {
+    let mut buffer = [0; ARC_LEN];
+    let oid = write_b128(&mut buffer, Arc::MAX);
+    assert_eq!(oid.arcs().collect::<Vec<_>>(), [Arc::MAX]);
+    assert_eq!(oid.len(), ARC_LEN);
+    assert_eq!(RelativeOid::from_bytes(oid.as_bytes()).unwrap(), &*oid);
+}
+
+#[test]
+fn max_arc_roundtrip() {
+    let text = format!(".1.{}", Arc::MAX);
+    let oid: RelativeOidVec = text.parse().unwrap();
+    assert_eq!(oid.to_string(), text);
+}
+
+#[test]
+fn decode_arc_overflow() {
+    let mut bytes = vec![0xff; ARC_LEN];
+    bytes.push(0x7f);
+    assert!(RelativeOid::from_bytes(&bytes).is_err());
+}
+
+#[cfg(not(feature = "arc-u128"))]
+#[test]
+fn u64_bounds() {
+    assert_eq!(Arc::MAX, u64::MAX);
+    let oid = RelativeOid::from_bytes(b"\x81\xff\xff\xff\xff\xff\xff\xff\xff\x7f").unwrap();
+    assert_eq!(oid.arcs().next(), Some(u64::MAX));
+    assert!(RelativeOid::from_bytes(b"\x82\x80\x80\x80\x80\x80\x80\x80\x80\x00").is_err());
+}
+
+#[cfg(feature = "arc-u128")]
+#[test]
+fn uuid_arc() {
+    use oid_str::AbsoluteOidVec;
+
+    let text = "2.25.329800735698586629295641978511506172918";
+    let oid: AbsoluteOidVec = text.parse().unwrap();
+    assert_eq!(oid.to_string(), text);
+    assert_eq!(oid.arcs().nth(2), Some(329800735698586629295641978511506172918));
+}
+
+#[cfg(feature = "arc-u128")]
+#[test]
+fn u128_bounds() {
+    assert_eq!(Arc::MAX, u128::MAX);
+    let result = "340282366920938463463374607431768211456".parse::<RelativeOidVec>();
+    assert!(result.is_err());
+}
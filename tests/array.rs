@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use oid_str::{
+    oid, AbsoluteOidArray, AbsoluteOidVec, ParseIntoError, RelativeOid, RelativeOidArray, RootOid, StaticAbsoluteOid,
+};
+
+#[test]
+fn absolute_build() {
+    let root = RootOid::from_u8(0x2b).unwrap();
+    let mut oid = AbsoluteOidArray::<6>::from_root(root).unwrap();
+    oid.push(6).unwrap();
+    oid.extend(RelativeOid::from_bytes(b"\x01\x04").unwrap()).unwrap();
+    assert_eq!(oid.as_bytes(), b"\x2b\x06\x01\x04");
+    assert_eq!(oid.capacity(), 6);
+
+    let err = oid.push(16384).unwrap_err();
+    assert_eq!(err.required, 7);
+    // unchanged after a failed push
+    assert_eq!(oid.to_string(), "1.3.6.1.4");
+    oid.push(1).unwrap();
+    assert_eq!(oid.to_string(), "1.3.6.1.4.1");
+}
+
+#[test]
+fn absolute_pop() {
+    let mut oid: AbsoluteOidArray<16> = "2.999.16384.0".parse().unwrap();
+    assert_eq!(oid.pop(), Some(0));
+    assert_eq!(oid.pop(), Some(16384));
+    assert_eq!(oid.pop(), None);
+    assert_eq!(oid.to_string(), "2.999");
+}
+
+#[test]
+fn relative() {
+    let mut oid = RelativeOidArray::<8>::default();
+    assert!(oid.is_empty());
+    assert_eq!(oid.pop(), None);
+    oid.push(128).unwrap();
+    oid.push(0).unwrap();
+    assert_eq!(oid.as_bytes(), b"\x81\x00\x00");
+    assert_eq!(oid.pop(), Some(0));
+    assert_eq!(format!("{:?}", oid), ".128");
+
+    let parsed: RelativeOidArray<8> = ".1.2".parse().unwrap();
+    let copied = RelativeOidArray::<3>::from_oid(&parsed).unwrap();
+    assert_eq!(copied.to_string(), ".1.2");
+    assert_eq!(RelativeOidArray::<1>::from_oid(&parsed).unwrap_err().required, 2);
+}
+
+#[test]
+fn from_str() {
+    let oid: AbsoluteOidArray<8> = "1.2.840.113549".parse().unwrap();
+    assert_eq!(&*oid, &*"1.2.840.113549".parse::<AbsoluteOidVec>().unwrap());
+
+    let err = "1.2.840.113549".parse::<AbsoluteOidArray<5>>().unwrap_err();
+    assert!(matches!(err, ParseIntoError::BufferTooSmall(e) if e.required == 6));
+    let err = "1.2.0840".parse::<AbsoluteOidArray<8>>().unwrap_err();
+    assert!(matches!(err, ParseIntoError::Parsing(_)));
+}
+
+#[test]
+fn copy_eq_hash_ord() {
+    const PKCS: StaticAbsoluteOid<'static> = oid!("1.2.840.113549");
+    let a = AbsoluteOidArray::<8>::from_oid(&PKCS).unwrap();
+    let b = a;
+    assert_eq!(a, b);
+
+    // stale bytes past the length don't matter
+    let mut c: AbsoluteOidArray<8> = "1.2.840.113549.1".parse().unwrap();
+    c.pop();
+    assert_eq!(a, c);
+    let set: HashSet<_> = vec![a, b, c].into_iter().collect();
+    assert_eq!(set.len(), 1);
+
+    let mut d = a;
+    d.push(1).unwrap();
+    let e: AbsoluteOidArray<8> = "1.2.841".parse().unwrap();
+    assert!(a < d && d < e);
+}
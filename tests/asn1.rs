@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+
+use oid_str::{Asn1ErrorKind, Asn1ParseError, AbsoluteOidVec};
+
+fn parse(s: &str) -> Result<String, Asn1ParseError> {
+    AbsoluteOidVec::parse_asn1(s).map(|oid| oid.to_string())
+}
+
+fn error(s: &str) -> (Asn1ErrorKind, std::ops::Range<usize>) {
+    let err = AbsoluteOidVec::parse_asn1(s).unwrap_err();
+    (err.kind, err.span)
+}
+
+#[test]
+fn forms() {
+    assert_eq!(parse("{ iso(1) identified-organization(3) dod(6) 1 }").unwrap(), "1.3.6.1");
+    assert_eq!(parse("{1 3 6 1}").unwrap(), "1.3.6.1");
+    assert_eq!(parse("{ iso member-body 840 113549 }").unwrap(), "1.2.840.113549");
+    assert_eq!(parse("{ itu-t recommendation x(24) }").unwrap(), "0.0.24");
+    assert_eq!(parse("{ joint-iso-itu-t ds(5) 29 17 }").unwrap(), "2.5.29.17");
+    assert_eq!(parse("  {\n\tiso (1)\n org(3) dod(6)\n}  ").unwrap(), "1.3.6");
+    assert_eq!(parse("{ 2 999 }").unwrap(), "2.999");
+    assert_eq!(parse("{ iso 0 }").unwrap(), "1.0");
+}
+
+#[test]
+fn name_checks() {
+    assert!(matches!(error("{ iso(2) 3 }"), (Asn1ErrorKind::NameMismatch, r) if r == (2..8)));
+    assert!(matches!(error("{ iso member-body(3) }"), (Asn1ErrorKind::NameMismatch, r) if r == (6..20)));
+    assert!(matches!(error("{ foo(1) 3 }"), (Asn1ErrorKind::NameMismatch, r) if r == (2..8)));
+    // names are only fixed for the first two arcs
+    assert_eq!(parse("{ 1 3 iso(6) }").unwrap(), "1.3.6");
+    assert!(matches!(error("{ 1 3 dod }"), (Asn1ErrorKind::UnknownName, r) if r == (6..9)));
+    assert!(matches!(error("{ 1 3 iso }"), (Asn1ErrorKind::UnknownName, r) if r == (6..9)));
+    assert!(matches!(error("{ joint-iso-itu-t ds 5 }"), (Asn1ErrorKind::UnknownName, r) if r == (18..20)));
+}
+
+#[test]
+fn syntax_errors() {
+    assert!(matches!(error(""), (Asn1ErrorKind::ExpectedOpenBrace, r) if r == (0..0)));
+    assert!(matches!(error("1.3.6"), (Asn1ErrorKind::ExpectedOpenBrace, r) if r == (0..1)));
+    assert!(matches!(error("{ 1 3"), (Asn1ErrorKind::Unterminated, r) if r == (5..5)));
+    assert!(matches!(error("{ 1 3 } x"), (Asn1ErrorKind::TrailingInput, r) if r == (8..9)));
+    assert!(matches!(error("{ 1, 3 }"), (Asn1ErrorKind::UnexpectedChar, r) if r == (3..4)));
+    assert!(matches!(error("{ 1 3 é }"), (Asn1ErrorKind::UnexpectedChar, r) if r == (6..8)));
+    assert!(matches!(error("{ iso(x) }"), (Asn1ErrorKind::UnexpectedChar, r) if r == (6..7)));
+    assert!(matches!(error("{ iso(1 }"), (Asn1ErrorKind::UnexpectedChar, r) if r == (8..9)));
+    assert!(matches!(error("{ 1 03 }"), (Asn1ErrorKind::LeadingZero, r) if r == (4..6)));
+    assert!(matches!(error("{ Iso 3 }"), (Asn1ErrorKind::InvalidName, r) if r == (2..5)));
+    assert!(matches!(error("{ 1 3 a--b(1) }"), (Asn1ErrorKind::InvalidName, r) if r == (6..10)));
+    assert!(matches!(error("{ 1 3 a-(1) }"), (Asn1ErrorKind::InvalidName, r) if r == (6..8)));
+}
+
+#[test]
+fn arc_errors() {
+    assert!(matches!(error("{ 3 1 }"), (Asn1ErrorKind::InvalidRoot, r) if r == (2..3)));
+    assert!(matches!(error("{ 1 40 }"), (Asn1ErrorKind::InvalidRoot, r) if r == (4..6)));
+    assert!(matches!(error("{ 1 }"), (Asn1ErrorKind::TooFewArcs, r) if r == (0..5)));
+    assert!(matches!(error("{ }"), (Asn1ErrorKind::TooFewArcs, _)));
+    let too_big = "{ 1 3 1000000000000000000000000000000000000000000 }";
+    assert!(matches!(error(too_big), (Asn1ErrorKind::OutOfRange, r) if r == (6..49)));
+}
+
+#[test]
+fn references() {
+    let mut defs = BTreeMap::new();
+    defs.insert("id-pkix".to_string(), "1.3.6.1.5.5.7".parse::<AbsoluteOidVec>().unwrap());
+    defs.insert("mib-2".to_string(), "1.3.6.1.2.1".parse::<AbsoluteOidVec>().unwrap());
+
+    let parse = |s| AbsoluteOidVec::parse_asn1_with(s, &defs).map(|oid| oid.to_string());
+    assert_eq!(parse("{ id-pkix 3 }").unwrap(), "1.3.6.1.5.5.7.3");
+    assert_eq!(parse("{ mib-2 system(1) 1 }").unwrap(), "1.3.6.1.2.1.1.1");
+    assert_eq!(parse("{ id-pkix }").unwrap(), "1.3.6.1.5.5.7");
+    // well-known names are not references
+    assert_eq!(parse("{ iso 3 }").unwrap(), "1.3");
+    // references are only allowed first
+    assert!(matches!(parse("{ 1 3 id-pkix }").unwrap_err().kind, Asn1ErrorKind::UnknownName));
+    let err = parse("{ id-pe 1 }").unwrap_err();
+    assert!(matches!(err.kind, Asn1ErrorKind::UnknownName));
+    assert_eq!(err.span, 2..7);
+    assert_eq!(err.to_string(), "unknown name at 2..7");
+}
+
+#[test]
+#[cfg(feature = "registry")]
+fn registry_references() {
+    use oid_str::registry::Registry;
+
+    let oid = AbsoluteOidVec::parse_asn1_with("{ id-kp 1 }", &Registry).unwrap();
+    assert_eq!(oid.to_string(), "1.3.6.1.5.5.7.3.1");
+}
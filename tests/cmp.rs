@@ -0,0 +1,109 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use oid_str::{
+    oid, AbsoluteOid, AbsoluteOidVec, Arc, RelativeOid, RelativeOidVec, RootOid, StaticAbsoluteOid,
+    UnboundedRelativeOidVec,
+};
+
+const OIDS: &[&str] = &[
+    "0.0",
+    "0.39",
+    "0.39.1",
+    "1.0",
+    "1.2.840",
+    "1.3.6.1",
+    "1.3.6.1.2",
+    "1.3.6.1.2.1",
+    "1.3.6.1.10",
+    "1.3.6.1.127",
+    "1.3.6.1.128",
+    "1.3.6.1.255",
+    "1.3.6.1.256",
+    "1.3.6.1.16383",
+    "1.3.6.1.16384",
+    "1.3.6.2",
+    "2.0",
+    "2.39",
+    "2.40",
+    "2.47",
+    "2.48",
+    "2.999",
+    "2.999.1",
+    "2.16383",
+];
+
+fn arcs(oid: &AbsoluteOid) -> Vec<Arc> {
+    let root = oid.root().subidentifier();
+    std::iter::once(root).chain(oid.tail().arcs()).collect()
+}
+
+#[test]
+fn absolute_order_matches_arc_order() {
+    let oids: Vec<AbsoluteOidVec> = OIDS.iter().map(|s| s.parse().unwrap()).collect();
+    for a in &oids {
+        for b in &oids {
+            assert_eq!(a.cmp(b), arcs(a).cmp(&arcs(b)), "{} <=> {}", a, b);
+        }
+    }
+}
+
+#[test]
+fn absolute_list_is_sorted() {
+    let oids: Vec<AbsoluteOidVec> = OIDS.iter().map(|s| s.parse().unwrap()).collect();
+    let mut sorted = oids.clone();
+    sorted.reverse();
+    sorted.sort();
+    assert_eq!(sorted, oids);
+}
+
+#[test]
+fn bytewise_order_differs() {
+    // 16384 is encoded as [0x81, 0x80, 0x00], 256 as [0x82, 0x00]
+    let a = RelativeOid::from_bytes(b"\x81\x80\x00").unwrap();
+    let b = RelativeOid::from_bytes(b"\x82\x00").unwrap();
+    assert!(a.as_bytes() < b.as_bytes());
+    assert_eq!(a.cmp(b), Ordering::Greater);
+}
+
+#[test]
+fn relative_order() {
+    let a: RelativeOidVec = ".1.2".parse().unwrap();
+    let b: RelativeOidVec = ".1.2.0".parse().unwrap();
+    let c: RelativeOidVec = ".1.10".parse().unwrap();
+    assert!(RelativeOid::empty() < &*a);
+    assert!(a < b);
+    assert!(b < c);
+}
+
+#[test]
+fn root_order() {
+    let roots: Vec<RootOid> = [0u8, 39, 40, 79, 80, 127].iter().map(|&b| RootOid::from_u8(b).unwrap()).collect();
+    assert!(roots.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn static_order() {
+    const A: StaticAbsoluteOid<'static> = oid!("1.3.6.1.2");
+    const B: StaticAbsoluteOid<'static> = oid!("1.3.6.1.10");
+    assert!(A < B);
+    assert!(A == oid!("1.3.6.1.2"));
+}
+
+#[test]
+fn unbounded_order() {
+    let a: UnboundedRelativeOidVec = ".1461501637330902918203684832716283019655932542976".parse().unwrap();
+    let b: UnboundedRelativeOidVec = ".2".parse().unwrap();
+    assert!(b < a);
+}
+
+#[test]
+fn btree_lookup_by_borrowed() {
+    let mut map = BTreeMap::new();
+    for (i, s) in OIDS.iter().enumerate() {
+        map.insert(s.parse::<AbsoluteOidVec>().unwrap(), i);
+    }
+    let key = AbsoluteOid::from_bytes(b"\x2b\x06\x01\x0a").unwrap();
+    assert_eq!(map.get(key), Some(&8));
+    assert_eq!(map.keys().map(|oid| oid.to_string()).collect::<Vec<_>>(), OIDS);
+}
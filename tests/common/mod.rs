@@ -0,0 +1,7 @@
+//! Helpers shared by the integration tests
+
+use oid_str::AbsoluteOidVec;
+
+pub fn oid(s: &str) -> AbsoluteOidVec {
+    s.parse().unwrap()
+}
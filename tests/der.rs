@@ -0,0 +1,111 @@
+#![cfg(feature = "der")]
+
+use std::convert::TryFrom;
+
+use const_oid::ObjectIdentifier;
+use der::{
+    asn1::{ContextSpecific, SetOfVec},
+    Decode, DecodeValue, Encode, EncodeValue, Header, Length, SliceReader, SliceWriter, Tag, TagMode, TagNumber,
+};
+use oid_str::{AbsoluteOid, AbsoluteOidVec, RelativeOid, RelativeOidVec, SmallAbsoluteOid, SmallRelativeOid};
+
+const RSA_TLV: &[u8] = b"\x06\x09\x2a\x86\x48\x86\xf7\x0d\x01\x01\x01";
+
+#[test]
+fn decode_encode_owned() {
+    let oid = AbsoluteOidVec::from_der(RSA_TLV).unwrap();
+    assert_eq!(oid.to_string(), "1.2.840.113549.1.1.1");
+    assert_eq!(oid.to_der().unwrap(), RSA_TLV);
+    assert_eq!(oid.encoded_len().unwrap(), Length::new(11));
+}
+
+#[test]
+fn decode_borrowed() {
+    let oid = <&AbsoluteOid>::from_der(RSA_TLV).unwrap();
+    assert_eq!(oid.as_bytes().as_ptr(), RSA_TLV[2..].as_ptr());
+    assert_eq!(oid.to_der().unwrap(), RSA_TLV);
+}
+
+#[test]
+fn invalid_content_is_rejected() {
+    let error = AbsoluteOidVec::from_der(b"\x06\x02\x2b\x86").unwrap_err();
+    assert_eq!(error.kind(), der::ErrorKind::Value { tag: Tag::ObjectIdentifier });
+    assert!(AbsoluteOidVec::from_der(b"\x06\x00").is_err());
+    assert!(AbsoluteOidVec::from_der(b"\x04\x01\x2b").is_err());
+}
+
+#[test]
+fn relative_value() {
+    let oid: RelativeOidVec = ".840.1".parse().unwrap();
+    assert_eq!(oid.value_len().unwrap(), Length::new(3));
+    let mut buf = [0u8; 3];
+    let mut writer = SliceWriter::new(&mut buf);
+    oid.encode_value(&mut writer).unwrap();
+    assert_eq!(writer.finish().unwrap(), b"\x86\x48\x01");
+
+    let header = Header::new(Tag::ContextSpecific { constructed: false, number: TagNumber::N0 }, 3u8).unwrap();
+    let mut reader = SliceReader::new(&buf).unwrap();
+    let decoded = <&RelativeOid>::decode_value(&mut reader, header).unwrap();
+    assert_eq!(decoded, &*oid);
+}
+
+#[test]
+fn implicit_tagging() {
+    let oid: AbsoluteOidVec = "1.3.6.1".parse().unwrap();
+    let field = ContextSpecific {
+        tag_number: TagNumber::N1,
+        tag_mode: TagMode::Implicit,
+        value: oid.clone(),
+    };
+    let der = field.to_der().unwrap();
+    assert_eq!(der, b"\x81\x03\x2b\x06\x01");
+}
+
+#[test]
+fn set_of_is_sorted_by_encoding() {
+    // encodings are of the same length, `a` sorts before `b` by encoding, but after it by arcs
+    let a: AbsoluteOidVec = "1.3.6.1.16384.1".parse().unwrap();
+    let b: AbsoluteOidVec = "1.3.6.1.256.1.1".parse().unwrap();
+    assert_eq!(a.as_bytes().len(), b.as_bytes().len());
+    assert!(a > b);
+    let set = SetOfVec::try_from(vec![b.clone(), a.clone()]).unwrap();
+    assert_eq!(set.as_slice(), [a, b]);
+}
+
+#[test]
+fn const_oid_conversions() {
+    let oid = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+    let owned = AbsoluteOidVec::try_from(oid).unwrap();
+    assert_eq!(owned.to_string(), "1.2.840.113549.1.1.1");
+    let borrowed = <&AbsoluteOid>::try_from(&oid).unwrap();
+    assert_eq!(borrowed, &*owned);
+    assert_eq!(ObjectIdentifier::try_from(&owned).unwrap(), oid);
+    assert_eq!(ObjectIdentifier::try_from(borrowed).unwrap(), oid);
+
+    // const-oid is limited to 39 bytes
+    let long: AbsoluteOidVec = format!("1.3{}", ".1".repeat(40)).parse().unwrap();
+    assert!(ObjectIdentifier::try_from(long).is_err());
+
+    // const-oid accepts padded arcs, we don't
+    let padded = ObjectIdentifier::from_bytes(b"\x2b\x06\x80\x01").unwrap();
+    assert!(AbsoluteOidVec::try_from(padded).is_err());
+}
+
+#[test]
+fn small_oids() {
+    let oid = SmallAbsoluteOid::from_der(RSA_TLV).unwrap();
+    assert!(oid.is_inline());
+    assert_eq!(oid.to_string(), "1.2.840.113549.1.1.1");
+    assert_eq!(oid.to_der().unwrap(), RSA_TLV);
+
+    let const_oid = ObjectIdentifier::try_from(&oid).unwrap();
+    assert_eq!(SmallAbsoluteOid::try_from(const_oid).unwrap(), oid);
+    let padded = ObjectIdentifier::from_bytes(b"\x2b\x06\x80\x01").unwrap();
+    assert!(SmallAbsoluteOid::try_from(padded).is_err());
+
+    let header = Header::new(Tag::ObjectIdentifier, 3u8).unwrap();
+    let mut reader = SliceReader::new(b"\x86\x48\x01").unwrap();
+    let relative = SmallRelativeOid::decode_value(&mut reader, header).unwrap();
+    assert_eq!(relative.to_string(), ".840.1");
+    assert_eq!(relative.value_len().unwrap(), Length::new(3));
+}
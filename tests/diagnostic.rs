@@ -0,0 +1,123 @@
+use core::ops::Range;
+
+use oid_str::{
+    AbsoluteOidVec, LeadingDot, OidParsingError, ParseDiagnostic, ParseErrorKind, ParseOptions, RelativeOidVec,
+};
+
+fn absolute(options: ParseOptions, s: &str) -> (ParseErrorKind, Range<usize>, usize) {
+    let error = options.diagnose_absolute(s).unwrap_err();
+    (error.kind, error.span, error.arc)
+}
+
+fn strict(s: &str) -> (ParseErrorKind, Range<usize>, usize) {
+    absolute(ParseOptions::new(), s)
+}
+
+#[test]
+fn arc_errors() {
+    assert_eq!(strict("3.1"), (ParseErrorKind::InvalidArc0, 0..1, 0));
+    assert_eq!(strict("1.40"), (ParseErrorKind::InvalidArc1, 2..4, 1));
+    assert_eq!(strict("1.3.6.99999999999999999999999999999999999999999"), (ParseErrorKind::ArcOverflow, 6..47, 3));
+    assert_eq!(strict("1.3.06"), (ParseErrorKind::LeadingZero, 4..6, 2));
+    assert_eq!(strict("1"), (ParseErrorKind::MissingArc1, 1..1, 1));
+    assert!(ParseOptions::new().diagnose_absolute("2.40.1").is_ok());
+}
+
+#[test]
+fn syntax_errors() {
+    assert_eq!(strict(""), (ParseErrorKind::EmptyArc, 0..0, 0));
+    assert_eq!(strict("."), (ParseErrorKind::EmptyArc, 1..1, 0));
+    assert_eq!(strict("1..3"), (ParseErrorKind::EmptyArc, 2..2, 1));
+    assert_eq!(strict("1.3."), (ParseErrorKind::TrailingDot, 3..4, 2));
+    assert_eq!(strict("1.3.+6"), (ParseErrorKind::InvalidChar, 4..5, 2));
+    // byte offsets, not char indices
+    assert_eq!(strict("1.٣.x"), (ParseErrorKind::InvalidChar, 2..4, 1));
+    assert_eq!(strict("1.3 "), (ParseErrorKind::InvalidChar, 3..4, 1));
+}
+
+#[test]
+fn options() {
+    let trim = ParseOptions::new().trim_whitespace(true);
+    assert_eq!(absolute(trim, "  1.3.\n"), (ParseErrorKind::TrailingDot, 5..6, 2));
+
+    let forbidden = ParseOptions::new().leading_dot(LeadingDot::Forbidden);
+    assert_eq!(absolute(forbidden, ".1.3"), (ParseErrorKind::UnexpectedLeadingDot, 0..1, 0));
+    let required = ParseOptions::new().leading_dot(LeadingDot::Required);
+    assert_eq!(absolute(required, "1.3"), (ParseErrorKind::MissingLeadingDot, 0..0, 0));
+
+    assert!(ParseOptions::lenient().diagnose_absolute("1.03").is_ok());
+    let error = ParseOptions::new().diagnose_relative(".1.02").unwrap_err();
+    assert_eq!((error.kind, error.span, error.arc), (ParseErrorKind::LeadingZero, 3..5, 1));
+}
+
+/// Kind and position, that a diagnostic of `error` must have
+fn expected(error: &OidParsingError) -> (&'static [ParseErrorKind], usize) {
+    match error {
+        OidParsingError::InvalidChar(pos) => (&[ParseErrorKind::InvalidChar], *pos),
+        OidParsingError::UnexpectedLeadingDot(pos) => (&[ParseErrorKind::UnexpectedLeadingDot], *pos),
+        OidParsingError::LeadingDotExpected(pos) => (&[ParseErrorKind::MissingLeadingDot], *pos),
+        OidParsingError::IntegerExpected(pos) => (&[ParseErrorKind::EmptyArc, ParseErrorKind::TrailingDot], *pos),
+        OidParsingError::LeadingZero(pos) => (&[ParseErrorKind::LeadingZero], *pos),
+        OidParsingError::OverflowError(range) => (&[ParseErrorKind::ArcOverflow], range.start),
+        OidParsingError::InvalidArc0(range) => (&[ParseErrorKind::InvalidArc0], range.start),
+        OidParsingError::InvalidArc1(range) => (&[ParseErrorKind::InvalidArc1], range.start),
+        OidParsingError::NoArc1 => (&[ParseErrorKind::MissingArc1], usize::MAX),
+        error => panic!("unexpected error {:?}", error),
+    }
+}
+
+fn check_agreement(error: OidParsingError, diagnostic: ParseDiagnostic, context: &str) {
+    let (kinds, pos) = expected(&error);
+    assert!(kinds.contains(&diagnostic.kind), "{} {:?} {:?}", context, error, diagnostic);
+    if let OidParsingError::OverflowError(range) | OidParsingError::InvalidArc0(range) | OidParsingError::InvalidArc1(range) =
+        &error
+    {
+        assert_eq!(&diagnostic.span, range, "{}", context);
+    }
+    // empty arcs point after the dot, that `IntegerExpected` points at
+    let span = diagnostic.span.start.saturating_sub(1)..diagnostic.span.end + 1;
+    assert!(pos == usize::MAX || span.contains(&pos), "{} {:?} {:?}", context, error, diagnostic);
+}
+
+#[test]
+fn agrees_with_parsing() {
+    let inputs = [
+        "", ".", "..", "1", "1.", ".1", "1.3", "1.3.", ".1.3.6", "1..3", "0.39", "0.40", "2.999", "3.0", "1.03",
+        "00", " 1.3 ", "1.x", "1.3.4294967296", "2.4294967295", "2.4294967215.1", "256.1", "1.3.6.01.x",
+        "  .1.3.\n", "\t1..2", "1.٣", "1.2.99999999999999999999.3",
+    ];
+    let options = [
+        ParseOptions::new(),
+        ParseOptions::lenient(),
+        ParseOptions::new().trim_whitespace(true),
+        ParseOptions::new().leading_dot(LeadingDot::Forbidden),
+        ParseOptions::new().leading_dot(LeadingDot::Required),
+        ParseOptions::lenient().trim_whitespace(true).leading_dot(LeadingDot::Required),
+    ];
+    for options in &options {
+        for s in inputs {
+            let context = format!("{:?} {:?}", s, options);
+            match (AbsoluteOidVec::parse_with(s, options), options.diagnose_absolute(s)) {
+                (Ok(_), Ok(())) => {}
+                (Err(error), Err(diagnostic)) => check_agreement(error, diagnostic, &context),
+                (parsed, diagnosed) => panic!("{} {:?} {:?}", context, parsed, diagnosed),
+            }
+            match (RelativeOidVec::parse_with(s, options), options.diagnose_relative(s)) {
+                (Ok(_), Ok(())) => {}
+                (Err(error), Err(diagnostic)) => check_agreement(error, diagnostic, &context),
+                (parsed, diagnosed) => panic!("{} {:?} {:?}", context, parsed, diagnosed),
+            }
+        }
+    }
+}
+
+#[test]
+fn render() {
+    let input = "1.3.6.1.00";
+    let error = ParseOptions::new().diagnose_absolute(input).unwrap_err();
+    assert_eq!(error.to_string(), "arc has leading zeros at 8..10 (arc 4)");
+    assert_eq!(error.render(input).to_string(), "1.3.6.1.00\n        ^^ arc has leading zeros");
+
+    let error = ParseOptions::new().diagnose_absolute("1").unwrap_err();
+    assert_eq!(error.render("1").to_string(), "1\n ^ absolute oid must have at least two arcs");
+}
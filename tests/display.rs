@@ -20,3 +20,11 @@ fn test_display2() {
     let arcs = oid.to_string();
     assert_eq!(arcs, "2.16.840.1.101.3.4.1.42");
 }
+
+#[test]
+fn test_display_multibyte_root() {
+    let oid: AbsoluteOidVec = "2.25.1".parse().unwrap();
+    assert_eq!(oid.as_bytes(), b"\x69\x01");
+    let oid: AbsoluteOidVec = "2.999.1".parse().unwrap();
+    assert_eq!(oid.to_string(), "2.999.1");
+}
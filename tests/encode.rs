@@ -1,4 +1,4 @@
-use oid_str::{ARC_LEN, Arc, write_b128};
+use oid_str::{ARC_LEN, write_b128};
 
 #[test]
 fn test_encode_small() {
@@ -29,9 +29,10 @@ fn test_encode_large_with_zeros() {
 }
 
 #[test]
+#[cfg(not(any(feature = "arc-u64", feature = "arc-u128")))]
 fn test_encode_maximal_size() {
     let mut buffer = [0; ARC_LEN];
-    assert_eq!(Arc::MAX, 0xffff_ffff);
+    assert_eq!(oid_str::Arc::MAX, 0xffff_ffff);
     let oid = write_b128(&mut buffer, 0xffff_ffff);
     assert_eq!(oid.as_bytes(), b"\x8f\xff\xff\xff\x7f");
 
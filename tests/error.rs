@@ -0,0 +1,83 @@
+use std::error::Error;
+
+use oid_str::{
+    parse_absolute, AbsoluteOid, AbsoluteOidVec, Arc0, Arc1, OidDecodingError, RelativeOid, RootOid,
+    TlvError,
+};
+
+#[test]
+fn decoding_error_messages() {
+    let error = AbsoluteOid::from_bytes(b"").unwrap_err();
+    assert_eq!(error.to_string(), "absolute oid must not be empty");
+
+    let error = AbsoluteOid::from_bytes(b"\x2b\x06\x80\x01").unwrap_err();
+    assert_eq!(error.to_string(), "invalid arc at byte 2");
+
+    let error = RelativeOid::from_bytes(b"\x01\x86").unwrap_err();
+    assert_eq!(error.to_string(), "arc is missing its terminating byte at byte 1");
+}
+
+#[test]
+fn decoding_error_source() {
+    let error = AbsoluteOid::from_bytes(b"\x2b\x06\x80\x01").unwrap_err();
+    let source = error.source().unwrap();
+    assert_eq!(source.to_string(), "arc starts with a redundant 0x80 byte at byte 2");
+    assert!(source.source().is_none());
+
+    let error = AbsoluteOid::from_bytes(b"\x80\x01").unwrap_err();
+    assert!(matches!(error, OidDecodingError::Root(_)));
+    let source = error.source().unwrap();
+    assert_eq!(source.to_string(), "invalid root of an absolute oid");
+    assert_eq!(source.source().unwrap().to_string(), "arc starts with a redundant 0x80 byte at byte 0");
+
+    let error = RootOid::new(Arc0::Iso, Arc1::new(40).unwrap()).unwrap_err();
+    assert!(error.b128_error().is_none());
+    assert!(error.source().is_none());
+
+    assert!(OidDecodingError::Empty.source().is_none());
+}
+
+#[test]
+fn tlv_error_source_chain() {
+    let error = AbsoluteOid::from_der_tlv(b"\x06\x02\x2b\x86").unwrap_err();
+    assert!(matches!(error, TlvError::Content(_)));
+    let chain: Vec<String> = std::iter::successors(Some(&error as &dyn Error), |&e| e.source())
+        .map(|e| e.to_string())
+        .collect();
+    assert_eq!(
+        chain,
+        [
+            "invalid oid content",
+            "invalid arc at byte 1",
+            "arc is missing its terminating byte at byte 1",
+        ]
+    );
+}
+
+#[test]
+fn parsing_error_messages() {
+    let error = "1.3.x".parse::<AbsoluteOidVec>().unwrap_err();
+    assert_eq!(error.to_string(), "invalid character at position 4");
+    let error = parse_absolute("1").err().unwrap();
+    assert_eq!(error.to_string(), "absolute oid must have at least two arcs");
+    let error = parse_absolute("3.1").err().unwrap();
+    assert_eq!(error.to_string(), "first arc at positions 0..1 must be 0, 1 or 2");
+    let error = parse_absolute("1.40").err().unwrap();
+    assert_eq!(error.to_string(), "second arc at positions 2..4 is out of range");
+}
+
+#[test]
+fn root_error_messages() {
+    assert_eq!(Arc0::new(3).unwrap_err().to_string(), "first arc must be 0, 1 or 2");
+    let error = Arc1::new(oid_str::Arc::MAX).unwrap_err();
+    assert_eq!(error.to_string(), format!("second arc must not exceed {}", oid_str::MAX_ARC1));
+}
+
+#[test]
+fn errors_box_into_dyn_error() {
+    fn parse(bytes: &[u8]) -> Result<String, Box<dyn Error>> {
+        Ok(AbsoluteOid::from_bytes(bytes)?.to_string())
+    }
+    assert_eq!(parse(b"\x2b\x06").unwrap(), "1.3.6");
+    assert!(parse(b"\x2b\x86").is_err());
+}
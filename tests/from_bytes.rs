@@ -1,9 +1,12 @@
-use oid_str::{AbsoluteOid, B128ErrorKind, OidDecodingError, RelativeOid, RootOid};
+use oid_str::{
+    AbsoluteOid, B128ErrorKind, OidDecodingError, RelativeOid, RootOid, StaticAbsoluteOid,
+    StaticRelativeOid,
+};
 
 #[test]
-fn root_cannot_be_build_from_too_large_bytes_120() {
-    let result = RootOid::from_u8(120u8);
-    assert!(result.is_err(), "root cannot be built from byte 120");
+fn root_from_byte_120_is_joint_iso_itu_t() {
+    let root = RootOid::from_u8(120u8).unwrap();
+    assert_eq!(root.to_string(), "2.40");
 }
 
 #[test]
@@ -20,6 +23,7 @@ fn relative_oid_with_x80_byte_in_the_middle() {
 }
 
 #[test]
+#[cfg(not(any(feature = "arc-u64", feature = "arc-u128")))]
 fn relative_oid_b128_error_overflow() {
     let bytes = b"\x90\x80\x80\x80\x00";
     let result = RelativeOid::from_bytes(bytes);
@@ -59,8 +63,9 @@ fn absolute_oid_empty() {
 }
 
 #[test]
+#[cfg(not(any(feature = "arc-u64", feature = "arc-u128")))]
 fn absolute_oid_root_overflow() {
-    let bytes = b"\x78";
+    let bytes = b"\x90\x80\x80\x80\x00";
     let result = AbsoluteOid::from_bytes(bytes);
     assert!(result.is_err(), "absolute oid root must be valid");
     let err = result.unwrap_err();
@@ -68,6 +73,49 @@ fn absolute_oid_root_overflow() {
 }
 
 #[test]
+fn absolute_oid_root_unfinished() {
+    let bytes = b"\x88";
+    let result = AbsoluteOid::from_bytes(bytes);
+    let err = result.unwrap_err();
+    assert!(matches!(err, OidDecodingError::Root(_)), "error is {:?}", err);
+}
+
+#[test]
+fn absolute_oid_multibyte_root() {
+    let oid = AbsoluteOid::from_bytes(b"\x88\x37\x01").unwrap();
+    assert_eq!(oid.to_string(), "2.999.1");
+    assert_eq!(oid.root().subidentifier(), 1079);
+    assert_eq!(oid.root().as_bytes(), b"\x88\x37");
+    assert_eq!(oid.tail().as_bytes(), b"\x01");
+}
+
+#[test]
+fn absolute_oid_b128_error_position_after_multibyte_root() {
+    let bytes = b"\x88\x37\x01\x80";
+    let err = AbsoluteOid::from_bytes(bytes).unwrap_err();
+    match err {
+        OidDecodingError::Base128(err) => {
+            assert!(matches!(err.kind, B128ErrorKind::ZeroByteWithCont), "kind is {:?}", err.kind);
+            assert_eq!(err.pos, 3);
+        }
+        _ => panic!("error is {:?}", err),
+    }
+}
+
+#[test]
+fn absolute_oid_b128_error_position_is_clamped() {
+    let mut bytes = vec![0x81, 0x00];
+    bytes.extend(core::iter::repeat(0x01).take(65535));
+    bytes.push(0x80);
+    let err = AbsoluteOid::from_bytes(&bytes).unwrap_err();
+    match err {
+        OidDecodingError::Base128(err) => assert_eq!(err.pos, u16::MAX),
+        _ => panic!("error is {:?}", err),
+    }
+}
+
+#[test]
+#[cfg(not(any(feature = "arc-u64", feature = "arc-u128")))]
 fn absolute_oid_b128_overflow() {
     let mut bytes = *b"\x43\x90\x80\x80\x80\x80";
     let result = AbsoluteOid::from_mut_bytes(&mut bytes);
@@ -90,4 +138,64 @@ fn relative_oid_from_mut_bytes() {
     let oid = RelativeOid::from_mut_bytes(&mut buffer).unwrap();
     let string = oid.to_string();
     assert_eq!(string, ".840.1.101.3.4.1.42");
-}
\ No newline at end of file
+}
+
+const CONST_ABSOLUTE: StaticAbsoluteOid<'static> =
+    StaticAbsoluteOid::from_bytes_const(b"\x2a\x86\x48\x86\xf7\x0d\x01\x01\x0b");
+const CONST_RELATIVE: StaticRelativeOid<'static> =
+    StaticRelativeOid::from_bytes_const(b"\x86\x48\x01");
+const _: () = assert!(StaticAbsoluteOid::from_bytes(b"\x2a\x80\x01").is_err());
+
+#[test]
+fn static_from_bytes_const() {
+    assert_eq!(CONST_ABSOLUTE.to_string(), "1.2.840.113549.1.1.11");
+    assert_eq!(CONST_RELATIVE.to_string(), ".840.1");
+}
+
+#[test]
+fn relative_errors_keep_scanning() {
+    // [01] [80 01] [ff ff ff ff ff 01] [02] [86]
+    let bytes = b"\x01\x80\x01\xff\xff\xff\xff\xff\x01\x02\x86";
+    let errors: Vec<_> = RelativeOid::errors(bytes)
+        .map(|(error, range)| (error.kind, error.pos, range))
+        .collect();
+    assert!(matches!(errors[0], (B128ErrorKind::ZeroByteWithCont, 1, ref r) if *r == (1..3)));
+    assert!(matches!(errors.last().unwrap(), (B128ErrorKind::Unfinished, 10, r) if *r == (10..11)));
+    #[cfg(not(any(feature = "arc-u64", feature = "arc-u128")))]
+    {
+        assert_eq!(errors.len(), 3);
+        assert!(matches!(errors[1], (B128ErrorKind::OutOfRange, 3, ref r) if *r == (3..9)));
+    }
+}
+
+#[test]
+fn first_error_matches_from_bytes() {
+    let inputs: &[&[u8]] = &[b"", b"\x01\x02", b"\x80", b"\x01\x86", b"\x2b\x80\x80\x01\x86"];
+    for bytes in inputs {
+        let first = RelativeOid::errors(bytes).next().map(|(error, _)| error.pos);
+        assert_eq!(first, RelativeOid::from_bytes(bytes).err().map(|error| error.pos));
+        let first = AbsoluteOid::errors(bytes).next().map(|(error, _)| format!("{:?}", error));
+        let error = AbsoluteOid::from_bytes(bytes).err().map(|error| format!("{:?}", error));
+        assert_eq!(first, error);
+    }
+}
+
+#[test]
+fn absolute_errors() {
+    assert!(matches!(
+        AbsoluteOid::errors(b"").collect::<Vec<_>>()[..],
+        [(OidDecodingError::Empty, ref r)] if r.is_empty()
+    ));
+    assert!(AbsoluteOid::errors(b"\x2b\x06\x01").next().is_none());
+
+    let errors: Vec<_> = AbsoluteOid::errors(b"\x80\x2b\x06\x80\x01\x86").collect();
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(
+        &errors[0],
+        (OidDecodingError::Root(e), r)
+            if matches!(e.b128_error(), Some(e) if matches!(e.kind, B128ErrorKind::ZeroByteWithCont) && e.pos == 0)
+                && *r == (0..2)
+    ));
+    assert!(matches!(&errors[1], (OidDecodingError::Base128(e), r) if e.pos == 3 && *r == (3..5)));
+    assert!(matches!(&errors[2], (OidDecodingError::Base128(e), r) if e.pos == 5 && *r == (5..6)));
+}
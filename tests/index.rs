@@ -166,6 +166,7 @@ fn index_start_is_inside_arc() {
 }
 
 #[test]
+#[allow(clippy::reversed_empty_ranges)]
 fn index_start_gt_end_get() {
     let oid = RelativeOid::from_bytes(b"\x01\x83\x81\x02").unwrap();
     assert!(oid.get(4..1).is_none());
@@ -173,6 +174,7 @@ fn index_start_gt_end_get() {
 
 #[test]
 #[should_panic = "start <= end (4 <= 1) when slicing `.1.49282`"]
+#[allow(clippy::reversed_empty_ranges)]
 fn index_start_gt_end() {
     let oid = RelativeOid::from_bytes(b"\x01\x83\x81\x02").unwrap();
     let _ = &oid[4..1];
@@ -0,0 +1,141 @@
+use std::convert::TryFrom;
+
+use oid_str::{
+    AbsoluteOidVec, Arc, EncodingRules, IriError, LabelResolver, OidIri, RelativeOidIri, Tag, TlvError,
+};
+
+fn iri(s: &str) -> OidIri {
+    s.parse().unwrap()
+}
+
+fn invalid(s: &str) -> usize {
+    match s.parse::<OidIri>() {
+        Err(IriError::InvalidLabel(pos)) => pos,
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn labels() {
+    let cbeff = iri("/ISO/Registration_Authority/19785.CBEFF");
+    assert_eq!(cbeff.labels().collect::<Vec<_>>(), ["ISO", "Registration_Authority", "19785.CBEFF"]);
+    assert_eq!(cbeff.as_str(), "/ISO/Registration_Authority/19785.CBEFF");
+    assert_eq!(cbeff.to_string(), "/ISO/Registration_Authority/19785.CBEFF");
+
+    iri("/0");
+    iri("/Joint-ISO-ITU-T/Example/a~b_c.d");
+    iri("/ISO/Преподаватель/日本");
+    iri("/ISO/123456789012345678901234567890123456789012345678901234567890");
+}
+
+#[test]
+fn invalid_labels() {
+    assert!(matches!("ISO/1".parse::<OidIri>(), Err(IriError::LeadingSlash)));
+    assert!(matches!("/ISO/1".parse::<RelativeOidIri>(), Err(IriError::LeadingSlash)));
+    assert_eq!(invalid("/"), 1);
+    assert_eq!(invalid("/ISO/"), 5);
+    assert_eq!(invalid("/ISO//1"), 5);
+    assert_eq!(invalid("/ISO/01"), 5);
+    assert_eq!(invalid("/ISO/-a"), 5);
+    assert_eq!(invalid("/ISO/a-"), 5);
+    assert_eq!(invalid("/ISO/ab--c"), 5);
+    assert_eq!(invalid("/ISO/a b"), 5);
+    assert_eq!(invalid("/ISO/a#b"), 5);
+    assert_eq!(invalid("/ISO/a\u{7f}"), 5);
+    assert_eq!(invalid("/ISO/a\u{fffe}"), 5);
+    // hyphens elsewhere are fine
+    iri("/ISO/a--b");
+    iri("/ISO/abc-d-e");
+}
+
+struct Labels;
+
+impl LabelResolver for Labels {
+    fn resolve_label(&self, parent: &[Arc], label: &str) -> Option<Arc> {
+        match (parent, label) {
+            ([1], "Registration_Authority") => Some(1),
+            ([1, 1], "19785.CBEFF") => Some(19785),
+            ([2], "Example") => Some(999),
+            ([2], "UUID") => Some(25),
+            ([2, 999], "Test") => Some(7),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn to_oid() {
+    let oid = iri("/ISO/Registration_Authority/19785.CBEFF").to_oid(&Labels).unwrap();
+    assert_eq!(oid.to_string(), "1.1.19785");
+    assert_eq!(iri("/Joint-ISO-ITU-T/Example/Test/3").to_oid(&Labels).unwrap().to_string(), "2.999.7.3");
+    // long arcs
+    assert_eq!(iri("/Example/Test").to_oid(&Labels).unwrap().to_string(), "2.999.7");
+    assert_eq!(iri("/UUID").to_oid(&Labels).unwrap().to_string(), "2.25");
+    // integer labels need no resolver
+    assert_eq!(iri("/1/3/6/1").to_oid(&()).unwrap().to_string(), "1.3.6.1");
+    assert_eq!(iri("/ITU-T/0").to_oid(&()).unwrap().to_string(), "0.0");
+
+    assert!(matches!(iri("/ISO/Unknown").to_oid(&Labels), Err(IriError::UnknownLabel(5))));
+    assert!(matches!(iri("/Nowhere/1").to_oid(&Labels), Err(IriError::UnknownLabel(1))));
+    assert!(matches!(iri("/ISO").to_oid(&Labels), Err(IriError::NoArc1)));
+    assert!(matches!(iri("/3/1").to_oid(&()), Err(IriError::InvalidRoot)));
+    assert!(matches!(iri("/1/40").to_oid(&()), Err(IriError::InvalidRoot)));
+    let huge = iri("/1/3/1000000000000000000000000000000000000000000");
+    assert!(matches!(huge.to_oid(&()), Err(IriError::OutOfRange(5))));
+}
+
+#[test]
+fn from_oid() {
+    let oid: AbsoluteOidVec = "2.999.1".parse().unwrap();
+    let iri = OidIri::from_oid(&oid);
+    assert_eq!(iri.as_str(), "/2/999/1");
+    assert_eq!(iri.to_oid(&()).unwrap(), oid);
+}
+
+#[test]
+fn relative() {
+    let rel: RelativeOidIri = "Registration_Authority/19785.CBEFF/2".parse().unwrap();
+    let iso: AbsoluteOidVec = "1.0".parse().unwrap();
+    assert!(matches!(rel.to_relative_oid(&iso, &Labels), Err(IriError::UnknownLabel(0))));
+    let example: AbsoluteOidVec = "2.999".parse().unwrap();
+    let rel: RelativeOidIri = "Test/5".parse().unwrap();
+    assert_eq!(rel.to_relative_oid(&example, &Labels).unwrap().to_string(), ".7.5");
+    let rel: RelativeOidIri = "Test/Unknown".parse().unwrap();
+    assert!(matches!(rel.to_relative_oid(&example, &Labels), Err(IriError::UnknownLabel(5))));
+}
+
+#[test]
+fn tlv() {
+    let cbeff = iri("/ISO/Registration_Authority/19785.CBEFF");
+    let der = cbeff.to_der_tlv();
+    assert_eq!(&der[..3], b"\x1f\x23\x27");
+    assert_eq!(&der[3..], cbeff.as_str().as_bytes());
+    let (decoded, rest) = OidIri::from_der_tlv(&der).unwrap();
+    assert_eq!(decoded, cbeff);
+    assert!(rest.is_empty());
+
+    let rel = RelativeOidIri::try_from("Test/5".to_string()).unwrap();
+    let der = rel.to_der_tlv();
+    assert_eq!(der, b"\x1f\x24\x06Test/5");
+    let mut buf = [0; 16];
+    let len = rel.write_der_tlv(&mut buf).unwrap();
+    assert_eq!(&buf[..len], &der[..]);
+    assert_eq!(RelativeOidIri::from_der_tlv(&der).unwrap().0, rel);
+
+    let (decoded, _) = OidIri::from_ber_tlv(b"\x1f\x23\x81\x02/1").unwrap();
+    assert_eq!(decoded.as_str(), "/1");
+    assert!(matches!(OidIri::from_der_tlv(b"\x1f\x23\x81\x02/1"), Err(TlvError::NonMinimalLength)));
+    assert!(matches!(OidIri::from_der_tlv(b"\x1f\x24\x02/1"), Err(TlvError::UnexpectedTag)));
+    assert!(matches!(
+        OidIri::from_der_tlv(b"\x1f\x23\x03/\xff1"),
+        Err(TlvError::IriContent(IriError::InvalidUtf8))
+    ));
+    assert!(matches!(
+        OidIri::from_der_tlv(b"\x1f\x23\x02/-"),
+        Err(TlvError::IriContent(IriError::InvalidLabel(1)))
+    ));
+
+    let implicit = Tag::context_specific(0);
+    let (decoded, _) = RelativeOidIri::from_tlv(b"\x80\x01a", implicit, EncodingRules::Der).unwrap();
+    assert_eq!(decoded.as_str(), "a");
+}
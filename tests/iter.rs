@@ -1,8 +1,9 @@
-use oid_str::{AbsoluteOid, Arc, RelativeOid};
+use oid_str::{AbsoluteOid, RelativeOid};
 
 #[test]
+#[cfg(not(any(feature = "arc-u64", feature = "arc-u128")))]
 fn iter_arcs_maximal_size() {
-    assert_eq!(Arc::MAX, 0xffff_ffff);
+    assert_eq!(oid_str::Arc::MAX, 0xffff_ffff);
     let oid = RelativeOid::from_bytes(b"\x8f\xff\xff\xff\x7f").unwrap();
     let arcs: Vec<_> = oid.arcs().collect();
     assert_eq!(arcs, &[0xffff_ffff]);
@@ -34,4 +35,19 @@ fn iter_large_with_zeros() {
     let oid = RelativeOid::from_bytes(b"\x81\x80\x00").unwrap();
     let arcs: Vec<_> = oid.arcs().collect();
     assert_eq!(arcs, &[128 * 128]);
-}
\ No newline at end of file
+}
+
+#[test]
+fn iter_absolute_arcs_multibyte_root() {
+    let oid = AbsoluteOid::from_bytes(b"\x88\x37\x01").unwrap();
+    let arcs: Vec<_> = oid.arcs().collect();
+    assert_eq!(arcs, [2, 999, 1]);
+}
+
+#[test]
+#[cfg(not(any(feature = "arc-u64", feature = "arc-u128")))]
+fn iter_absolute_arcs_max_root() {
+    let oid = AbsoluteOid::from_bytes(b"\x8f\xff\xff\xff\x7f\x01").unwrap();
+    let arcs: Vec<_> = oid.arcs().collect();
+    assert_eq!(arcs, [2, oid_str::Arc::MAX - 80, 1]);
+}
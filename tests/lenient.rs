@@ -0,0 +1,77 @@
+use oid_str::{AbsoluteOid, AbsoluteOidVec, B128ErrorKind, OidDecodingError, RelativeOid, RelativeOidVec};
+
+#[test]
+fn strict_decoding_rejects_padding() {
+    assert!(AbsoluteOid::from_bytes(b"\x2b\x80\x06").is_err());
+}
+
+#[test]
+fn relative_padding_is_removed() {
+    let mut bytes = *b"\x01\x80\x80\x86\x48\x80\x00\x02";
+    let (oid, normalized) = RelativeOid::normalize_ber(&mut bytes).unwrap();
+    assert!(normalized);
+    assert_eq!(oid.as_bytes(), b"\x01\x86\x48\x00\x02");
+    assert_eq!(oid.to_string(), ".1.840.0.2");
+}
+
+#[test]
+fn canonical_input_is_not_normalized() {
+    let mut bytes = *b"\x2a\x86\x48\x86\xf7\x0d";
+    let (oid, normalized) = AbsoluteOid::normalize_ber(&mut bytes).unwrap();
+    assert!(!normalized);
+    assert_eq!(oid.to_string(), "1.2.840.113549");
+
+    let mut empty = [];
+    let (oid, normalized) = RelativeOid::normalize_ber(&mut empty).unwrap();
+    assert!(!normalized);
+    assert!(oid.is_empty());
+}
+
+#[test]
+fn absolute_root_padding_is_removed() {
+    let mut bytes = *b"\x80\x88\x37\x80\x01";
+    let (oid, normalized) = AbsoluteOid::normalize_ber(&mut bytes).unwrap();
+    assert!(normalized);
+    assert_eq!(oid.to_string(), "2.999.1");
+}
+
+#[test]
+fn errors_leave_bytes_unchanged() {
+    let mut bytes = *b"\x2b\x80\x06\x80\x86";
+    let error = AbsoluteOid::normalize_ber(&mut bytes).unwrap_err();
+    assert!(matches!(error, OidDecodingError::Base128(ref e) if e.pos == 3));
+    assert!(matches!(error, OidDecodingError::Base128(ref e) if matches!(e.kind, B128ErrorKind::Unfinished)));
+    assert_eq!(&bytes, b"\x2b\x80\x06\x80\x86");
+
+    let mut bytes = *b"\x80\x80";
+    let error = RelativeOid::normalize_ber(&mut bytes).unwrap_err();
+    assert!(matches!(error.kind, B128ErrorKind::Unfinished));
+
+    assert!(matches!(AbsoluteOid::normalize_ber(&mut []), Err(OidDecodingError::Empty)));
+    assert!(matches!(
+        AbsoluteOid::normalize_ber(&mut [0x80]),
+        Err(OidDecodingError::Root(e)) if matches!(e.b128_error().unwrap().kind, B128ErrorKind::Unfinished)
+    ));
+}
+
+#[cfg(not(any(feature = "arc-u64", feature = "arc-u128")))]
+#[test]
+fn padding_does_not_count_towards_range() {
+    // u32::MAX with padding
+    let (oid, normalized) = RelativeOidVec::from_ber_lenient(b"\x80\x80\x8f\xff\xff\xff\x7f").unwrap();
+    assert!(normalized);
+    assert_eq!(oid.to_string(), ".4294967295");
+    let error = RelativeOidVec::from_ber_lenient(b"\x80\x90\x80\x80\x80\x00").unwrap_err();
+    assert!(matches!(error.kind, B128ErrorKind::OutOfRange), "kind is {:?}", error.kind);
+    assert_eq!(error.pos, 0);
+}
+
+#[test]
+fn owned_lenient() {
+    let (oid, normalized) = AbsoluteOidVec::from_ber_lenient(b"\x2b\x06\x80\x01").unwrap();
+    assert!(normalized);
+    assert_eq!(oid, "1.3.6.1".parse().unwrap());
+    let (oid, normalized) = RelativeOidVec::from_ber_lenient(b"\x06\x01").unwrap();
+    assert!(!normalized);
+    assert_eq!(oid.as_bytes(), b"\x06\x01");
+}
@@ -1,5 +1,6 @@
 use oid_str::{AbsoluteOid, AbsoluteOidVec, Arc0, Arc1, RelativeOid, RootOid};
 
+mod common;
 mod parse;
 mod from_bytes;
 mod encode;
@@ -7,15 +8,54 @@ mod iter;
 mod display;
 mod index;
 mod ops;
+mod arc_width;
+mod unbounded;
+mod tlv;
+mod macros;
+mod cmp;
+mod error;
+mod lenient;
+mod serde;
+mod der;
+mod map;
+mod set;
+mod registry;
+mod named;
+mod asn1;
+mod iri;
+mod diagnostic;
+mod parse_into;
+mod parse_options;
+mod urn;
+mod array;
+mod small;
+mod arc_mut;
 
 #[test]
 fn test_vec_from_root() {
-    let root = RootOid::new(Arc0::Iso, Arc1::new(3).unwrap());
+    let root = RootOid::new(Arc0::Iso, Arc1::new(3).unwrap()).unwrap();
     let oid = AbsoluteOidVec::from_root(root);
 
     assert_eq!(oid.as_bytes(), b"\x2B");
 }
 
+#[test]
+fn test_vec_from_multibyte_root() {
+    let root = RootOid::new(Arc0::JointIsoItuT, Arc1::new(999).unwrap()).unwrap();
+    let oid = AbsoluteOidVec::from_root(root);
+
+    assert_eq!(oid.as_bytes(), b"\x88\x37");
+    assert_eq!(oid.root(), root);
+    assert!(oid.tail().is_empty());
+}
+
+#[test]
+fn root_arc1_above_39_requires_joint_iso_itu_t() {
+    let arc1 = Arc1::new(40).unwrap();
+    assert!(RootOid::new(Arc0::Iso, arc1).is_err());
+    assert!(RootOid::new(Arc0::JointIsoItuT, arc1).is_ok());
+}
+
 #[test]
 fn absolute_mut_tail() {
     let mut buffer1 = *b"\x60\x86\x48\x01\x65\x03\x04\x01\x2A";
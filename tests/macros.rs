@@ -0,0 +1,53 @@
+use oid_str::{oid, relative_oid, AbsoluteOid, AbsoluteOidVec, RelativeOid, StaticAbsoluteOid, StaticRelativeOid};
+
+const SHA256_WITH_RSA: StaticAbsoluteOid<'static> = oid!("1.2.840.113549.1.1.11");
+static ECDSA_WITH_SHA256: StaticAbsoluteOid<'static> = oid!(".1.2.840.10045.4.3.2");
+const ID_CE: StaticAbsoluteOid<'static> = oid!("2.5.29");
+const JOINT: StaticAbsoluteOid<'static> = oid!("2.999");
+const ROOT_ONLY: StaticAbsoluteOid<'static> = oid!("0.0");
+
+const SUFFIX: StaticRelativeOid<'static> = relative_oid!(".1.1");
+const EMPTY: StaticRelativeOid<'static> = relative_oid!("");
+const EMPTY_DOT: StaticRelativeOid<'static> = relative_oid!(".");
+const LARGE: StaticRelativeOid<'static> = relative_oid!("4294967295.128");
+
+const TEXT: &str = "1.3.6.1";
+const FROM_CONST: StaticAbsoluteOid<'static> = oid!(TEXT);
+
+#[test]
+fn absolute_macro() {
+    let expected = AbsoluteOid::from_bytes(b"\x2a\x86\x48\x86\xf7\x0d\x01\x01\x0b").unwrap();
+    assert_eq!(&*SHA256_WITH_RSA, expected);
+    assert_eq!(ECDSA_WITH_SHA256.to_string(), "1.2.840.10045.4.3.2");
+    assert_eq!(ID_CE.as_bytes(), b"\x55\x1d");
+    assert_eq!(JOINT.as_bytes(), b"\x88\x37");
+    assert_eq!(ROOT_ONLY.as_bytes(), b"\x00");
+    assert_eq!(FROM_CONST.to_string(), TEXT);
+}
+
+#[test]
+fn absolute_macro_matches_parse() {
+    for (oid, text) in [
+        (&*SHA256_WITH_RSA, "1.2.840.113549.1.1.11"),
+        (&*ECDSA_WITH_SHA256, "1.2.840.10045.4.3.2"),
+        (&*JOINT, "2.999"),
+    ] {
+        let parsed: AbsoluteOidVec = text.parse().unwrap();
+        assert_eq!(oid, &*parsed);
+    }
+}
+
+#[test]
+fn relative_macro() {
+    let expected = RelativeOid::from_bytes(b"\x01\x01").unwrap();
+    assert_eq!(&*SUFFIX, expected);
+    assert!(EMPTY.is_empty());
+    assert!(EMPTY_DOT.is_empty());
+    assert_eq!(LARGE.as_bytes(), b"\x8f\xff\xff\xff\x7f\x81\x00");
+}
+
+#[test]
+fn macro_in_expression() {
+    let oid = oid!("1.3.6.1.4.1");
+    assert_eq!(oid.as_bytes(), b"\x2b\x06\x01\x04\x01");
+}
@@ -0,0 +1,125 @@
+use oid_str::{AbsoluteOid, AbsoluteOidVec, OidMap};
+
+use crate::common::oid;
+
+fn sample() -> OidMap<&'static str> {
+    let mut map = OidMap::new();
+    for (key, value) in [
+        ("1.3.6.1.2.1", "mib-2"),
+        ("1.3.6.1.2.1.1", "system"),
+        ("1.3.6.1.2.1.1.1.0", "sysDescr"),
+        ("1.3.6.1.2.1.1.5.0", "sysName"),
+        ("1.3.6.1.2.1.2", "interfaces"),
+        ("1.3.6.1.2.1.10", "transmission"),
+        ("1.3.6.1.2.1.128", "large"),
+        ("1.3.6.1.4.1", "enterprises"),
+        ("2.999", "example"),
+    ] {
+        map.insert(&oid(key), value);
+    }
+    map
+}
+
+fn keys<'a>(iter: impl Iterator<Item = (&'a AbsoluteOid, &'a &'static str)>) -> Vec<String> {
+    iter.map(|(key, _)| key.to_string()).collect()
+}
+
+#[test]
+fn insert_get_remove() {
+    let mut map = sample();
+    assert_eq!(map.len(), 9);
+    assert_eq!(map.get(&oid("1.3.6.1.2.1.1")), Some(&"system"));
+    assert_eq!(map.get(&oid("1.3.6.1.2.1.3")), None);
+    assert_eq!(map.insert(&oid("1.3.6.1.2.1.1"), "sys"), Some("system"));
+    assert_eq!(map.len(), 9);
+    assert_eq!(map.remove(&oid("1.3.6.1.2.1.1")), Some("sys"));
+    assert!(!map.contains_key(&oid("1.3.6.1.2.1.1")));
+}
+
+#[test]
+fn iteration_is_in_arc_order() {
+    let map = sample();
+    assert_eq!(
+        keys(map.iter()),
+        [
+            "1.3.6.1.2.1",
+            "1.3.6.1.2.1.1",
+            "1.3.6.1.2.1.1.1.0",
+            "1.3.6.1.2.1.1.5.0",
+            "1.3.6.1.2.1.2",
+            "1.3.6.1.2.1.10",
+            "1.3.6.1.2.1.128",
+            "1.3.6.1.4.1",
+            "2.999",
+        ]
+    );
+}
+
+#[test]
+fn longest_prefix() {
+    let map = sample();
+    let find = |s: &str| map.longest_prefix(&oid(s)).map(|(key, value)| (key.to_string(), *value));
+    assert_eq!(find("1.3.6.1.2.1.1.1.0"), Some(("1.3.6.1.2.1.1.1.0".into(), "sysDescr")));
+    assert_eq!(find("1.3.6.1.2.1.1.3.0"), Some(("1.3.6.1.2.1.1".into(), "system")));
+    assert_eq!(find("1.3.6.1.2.1.100"), Some(("1.3.6.1.2.1".into(), "mib-2")));
+    assert_eq!(find("2.999.5"), Some(("2.999".into(), "example")));
+    assert_eq!(find("1.3.6.1.2"), None);
+    assert_eq!(find("1.3.6.1.2.2"), None);
+}
+
+#[test]
+fn subtree() {
+    let map = sample();
+    let prefix = oid("1.3.6.1.2.1.1");
+    assert_eq!(
+        keys(map.subtree(&prefix)),
+        ["1.3.6.1.2.1.1", "1.3.6.1.2.1.1.1.0", "1.3.6.1.2.1.1.5.0"]
+    );
+    let prefix = oid("1.3.6.1.2.1.12");
+    assert!(map.subtree(&prefix).next().is_none());
+    // 1.3.6.1.2.1.1 is not a subtree of 1.3.6.1.2.1.128, even though it is a byte prefix
+    let prefix = oid("1.3.6.1.2.1.128");
+    assert_eq!(keys(map.subtree(&prefix)), ["1.3.6.1.2.1.128"]);
+    let prefix = oid("1.3");
+    assert_eq!(map.subtree(&prefix).count(), 8);
+}
+
+#[test]
+fn remove_subtree() {
+    let mut map = sample();
+    let removed = map.remove_subtree(&oid("1.3.6.1.2.1.1"));
+    assert_eq!(keys(removed.iter()).len(), 3);
+    assert_eq!(
+        keys(map.iter()),
+        [
+            "1.3.6.1.2.1",
+            "1.3.6.1.2.1.2",
+            "1.3.6.1.2.1.10",
+            "1.3.6.1.2.1.128",
+            "1.3.6.1.4.1",
+            "2.999",
+        ]
+    );
+    let removed = map.remove_subtree(&oid("2.998"));
+    assert!(removed.is_empty());
+    let removed = map.remove_subtree(&oid("2.999"));
+    assert_eq!(removed.len(), 1);
+    assert_eq!(map.len(), 5);
+}
+
+#[test]
+fn get_next_walks_the_tree() {
+    let map = sample();
+    let mut walk = Vec::new();
+    let mut current = oid("0.0");
+    while let Some((next, _)) = map.get_next(&current) {
+        walk.push(next.to_string());
+        current = AbsoluteOidVec::from_oid(next);
+    }
+    assert_eq!(walk, keys(map.iter()));
+
+    // successor of an absent oid
+    let (next, _) = map.get_next(&oid("1.3.6.1.2.1.1.2")).unwrap();
+    assert_eq!(next.to_string(), "1.3.6.1.2.1.1.5.0");
+    assert!(map.get_next(&oid("2.999")).is_none());
+}
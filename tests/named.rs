@@ -0,0 +1,86 @@
+use oid_str::{AbsoluteOid, AbsoluteOidArray, AbsoluteOidVec, NameTable, OidMap, SmallAbsoluteOid};
+
+use crate::common::oid;
+
+fn names() -> OidMap<&'static str> {
+    let mut names = OidMap::new();
+    names.insert(&oid("1.3"), "identified-organization");
+    names.insert(&oid("1.3.6"), "dod");
+    names.insert(&oid("1.3.6.1"), "internet");
+    names.insert(&oid("1.3.6.1.4.1"), "enterprises");
+    names
+}
+
+#[test]
+fn dotted() {
+    let names = names();
+    let oid = oid("1.3.6.1.4.1.311");
+    assert_eq!(
+        oid.display_with(&names).to_string(),
+        "iso(1).identified-organization(3).dod(6).internet(1).4.enterprises(1).311"
+    );
+    assert_eq!(oid.to_string(), "1.3.6.1.4.1.311");
+}
+
+#[test]
+fn asn1_value_notation() {
+    let names = names();
+    assert_eq!(
+        format!("{:#}", oid("1.3.6.1.2").display_with(&names)),
+        "{ iso(1) identified-organization(3) dod(6) internet(1) 2 }"
+    );
+    assert_eq!(format!("{:#}", oid("2.999").display_with(&names)), "{ joint-iso-itu-t(2) 999 }");
+    assert_eq!(format!("{}", oid("0.0").display_with(&names)), "itu-t(0).0");
+}
+
+struct Numbers;
+
+impl NameTable for Numbers {
+    fn name(&self, oid: &AbsoluteOid) -> Option<&str> {
+        match oid.arcs().last() {
+            Some(1) => Some("one"),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn custom_table() {
+    assert_eq!(oid("1.1.2.1").display_with(&Numbers).to_string(), "iso(1).one(1).2.one(1)");
+}
+
+#[test]
+#[cfg(not(feature = "registry"))]
+fn alternate_without_registry() {
+    assert_eq!(format!("{:#}", oid("1.2.840.113549")), "iso(1).2.840.113549");
+}
+
+#[test]
+#[cfg(feature = "registry")]
+fn alternate_with_registry() {
+    assert_eq!(format!("{:#}", oid("1.2.840.113549")), "iso(1).member-body(2).us(840).rsadsi(113549)");
+    assert_eq!(
+        format!("{:#}", oid("1.3.6.1.2.1.1.1.0")),
+        "iso(1).identified-organization(3).dod(6).internet(1).mgmt(2).mib-2(1).system(1).sysDescr(1).0"
+    );
+}
+
+#[test]
+fn pretty_debug_stays_numeric() {
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct S {
+        oid: AbsoluteOidVec,
+        small: SmallAbsoluteOid,
+        array: AbsoluteOidArray<8>,
+    }
+    let s = S {
+        oid: oid("1.2.840.113549"),
+        small: "1.2.840.113549".parse().unwrap(),
+        array: "1.2.840.113549".parse().unwrap(),
+    };
+    let debug = format!("{:#?}", s);
+    assert!(!debug.contains("iso"), "{}", debug);
+    assert_eq!(debug.matches("1.2.840.113549,").count(), 3, "{}", debug);
+    assert_eq!(format!("{:#?}", &*s.oid), "1.2.840.113549");
+}
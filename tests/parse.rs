@@ -1,4 +1,4 @@
-use oid_str::{AbsoluteOid, AbsoluteOidVec, Arc, OidParsingError, RelativeOidVec};
+use oid_str::{AbsoluteOid, AbsoluteOidVec, OidParsingError, RelativeOidVec};
 
 
 #[test]
@@ -96,7 +96,7 @@ fn parse_absolute_failure_no_arc1_with_dot() {
 fn parse_absolute_failure_no_arc0_overflow() {
     let result = ".3.6".parse::<AbsoluteOidVec>().unwrap_err();
     assert!(
-        matches!(result, OidParsingError::OverflowError),
+        matches!(result, OidParsingError::InvalidArc0(ref r) if *r == (1..2)),
         "result is {:?}",
         result
     );
@@ -106,7 +106,29 @@ fn parse_absolute_failure_no_arc0_overflow() {
 fn parse_absolute_failure_arc1_overflow() {
     let result = ".1.40".parse::<AbsoluteOidVec>().unwrap_err();
     assert!(
-        matches!(result, OidParsingError::OverflowError),
+        matches!(result, OidParsingError::InvalidArc1(ref r) if *r == (3..5)),
+        "result is {:?}",
+        result
+    );
+}
+
+#[test]
+fn parse_absolute_joint_iso_itu_t_large_arc1() {
+    let oid: AbsoluteOidVec = "2.999.1".parse().unwrap();
+    assert_eq!(oid.as_bytes(), b"\x88\x37\x01");
+    assert_eq!(oid.tail().as_bytes(), b"\x01");
+}
+
+#[test]
+#[cfg(not(any(feature = "arc-u64", feature = "arc-u128")))]
+fn parse_absolute_joint_iso_itu_t_arc1_overflow() {
+    assert_eq!(oid_str::Arc::MAX, 4294967295);
+    let oid: AbsoluteOidVec = "2.4294967215".parse().unwrap();
+    assert_eq!(oid.as_bytes(), b"\x8f\xff\xff\xff\x7f");
+
+    let result = "2.4294967216".parse::<AbsoluteOidVec>().unwrap_err();
+    assert!(
+        matches!(result, OidParsingError::InvalidArc1(ref r) if *r == (2..12)),
         "result is {:?}",
         result
     );
@@ -147,18 +169,20 @@ fn parse_relative() {
 }
 
 #[test]
+#[cfg(not(any(feature = "arc-u64", feature = "arc-u128")))]
 fn parse_relative_max_value() {
-    assert_eq!(Arc::MAX, 4294967295);
+    assert_eq!(oid_str::Arc::MAX, 4294967295);
     let oid: RelativeOidVec = "1.4294967295".parse().unwrap();
     assert_eq!(oid.as_bytes(), b"\x01\x8f\xff\xff\xff\x7f")
 }
 
 #[test]
+#[cfg(not(any(feature = "arc-u64", feature = "arc-u128")))]
 fn parse_relative_arc_overflow() {
-    assert_eq!(Arc::MAX, 4294967295);
+    assert_eq!(oid_str::Arc::MAX, 4294967295);
     let result = "1.4294967296".parse::<RelativeOidVec>().unwrap_err();
     assert!(
-        matches!(result, OidParsingError::OverflowError),
+        matches!(result, OidParsingError::OverflowError(ref r) if *r == (2..12)),
         "result is {:?}",
         result
     );
@@ -0,0 +1,55 @@
+use oid_str::{AbsoluteOid, AbsoluteOidVec, OidParsingError, ParseIntoError, RelativeOid, RelativeOidVec};
+
+#[test]
+fn absolute_matches_vec() {
+    for s in ["0.0", "1.3.6.1.4.1", ".2.999.3", "2.25.4294967295"] {
+        let vec: AbsoluteOidVec = s.parse().unwrap();
+        let mut buf = [0xffu8; 32];
+        let len = AbsoluteOid::parsed_len(s).unwrap();
+        let oid = AbsoluteOid::parse_into(s, &mut buf).unwrap();
+        assert_eq!(oid.as_bytes(), vec.as_bytes());
+        assert_eq!(len, vec.as_bytes().len());
+    }
+}
+
+#[test]
+fn relative_matches_vec() {
+    for s in ["", ".1", "1.0.128", ".16383.16384"] {
+        let vec: RelativeOidVec = s.parse().unwrap();
+        let mut buf = [0u8; 16];
+        assert_eq!(RelativeOid::parsed_len(s).unwrap(), vec.as_bytes().len());
+        let oid = RelativeOid::parse_into(s, &mut buf).unwrap();
+        assert_eq!(oid.as_bytes(), vec.as_bytes());
+    }
+}
+
+#[test]
+fn exact_buffer() {
+    let s = "2.999.1234567";
+    let mut buf = [0u8; 5];
+    assert_eq!(AbsoluteOid::parsed_len(s).unwrap(), 5);
+    let oid = AbsoluteOid::parse_into(s, &mut buf).unwrap();
+    assert_eq!(oid.to_string(), s);
+}
+
+#[test]
+fn buffer_too_small() {
+    let mut buf = [0u8; 4];
+    let error = AbsoluteOid::parse_into("2.999.1234567", &mut buf).err().unwrap();
+    assert!(matches!(error, ParseIntoError::BufferTooSmall(ref e) if e.required == 5));
+    assert_eq!(error.to_string(), "oid doesn't fit into the buffer");
+
+    let error = RelativeOid::parse_into(".128", &mut []).err().unwrap();
+    assert!(matches!(error, ParseIntoError::BufferTooSmall(e) if e.required == 2));
+}
+
+#[test]
+fn parsing_errors_come_first() {
+    let mut buf = [0u8; 0];
+    let error = AbsoluteOid::parse_into("1.3.06", &mut buf).err().unwrap();
+    assert!(matches!(error, ParseIntoError::Parsing(OidParsingError::LeadingZero(4))));
+    assert!(matches!(
+        RelativeOid::parsed_len("1.x").err().unwrap(),
+        OidParsingError::InvalidChar(2)
+    ));
+}
@@ -0,0 +1,77 @@
+use oid_str::{AbsoluteOidVec, LeadingDot, OidParsingError, ParseOptions, RelativeOidVec};
+
+fn absolute(options: ParseOptions, s: &str) -> Result<AbsoluteOidVec, OidParsingError> {
+    AbsoluteOidVec::parse_with(s, &options)
+}
+
+#[test]
+fn strict_by_default() {
+    assert!(matches!(
+        "1.3.06".parse::<AbsoluteOidVec>().err().unwrap(),
+        OidParsingError::LeadingZero(4)
+    ));
+    assert!(matches!(
+        "00.1".parse::<RelativeOidVec>().err().unwrap(),
+        OidParsingError::LeadingZero(0)
+    ));
+    assert!(matches!(
+        ".1.02".parse::<AbsoluteOidVec>().err().unwrap(),
+        OidParsingError::LeadingZero(3)
+    ));
+    assert!(" 1.3".parse::<AbsoluteOidVec>().is_err());
+    assert!("1.+3".parse::<AbsoluteOidVec>().is_err());
+    assert!("1.٣".parse::<AbsoluteOidVec>().is_err());
+    assert_eq!("1.0.0".parse::<AbsoluteOidVec>().unwrap().to_string(), "1.0.0");
+    assert_eq!(".1.3".parse::<AbsoluteOidVec>().unwrap().to_string(), "1.3");
+    assert_eq!(
+        "1.3.06".parse::<AbsoluteOidVec>().err().unwrap().to_string(),
+        "arc with leading zeros at position 4"
+    );
+}
+
+#[test]
+fn lenient() {
+    let oid = absolute(ParseOptions::lenient(), "1.03.006").unwrap();
+    assert_eq!(oid.to_string(), "1.3.6");
+
+    let rel = RelativeOidVec::parse_with(".007.0", &ParseOptions::lenient()).unwrap();
+    assert_eq!(rel.to_string(), ".7.0");
+}
+
+#[test]
+fn leading_dot() {
+    let required = ParseOptions::new().leading_dot(LeadingDot::Required);
+    assert!(absolute(required, ".1.3").is_ok());
+    assert!(matches!(
+        absolute(required, "1.3").err().unwrap(),
+        OidParsingError::LeadingDotExpected(0)
+    ));
+
+    let forbidden = ParseOptions::new().leading_dot(LeadingDot::Forbidden);
+    assert!(absolute(forbidden, "1.3").is_ok());
+    assert!(matches!(
+        absolute(forbidden, ".1.3").err().unwrap(),
+        OidParsingError::UnexpectedLeadingDot(0)
+    ));
+
+    let trim = ParseOptions::new().leading_dot(LeadingDot::Forbidden).trim_whitespace(true);
+    let error = absolute(trim, "  .1.3").err().unwrap();
+    assert!(matches!(error, OidParsingError::UnexpectedLeadingDot(2)));
+    assert_eq!(error.to_string(), "unexpected leading dot at position 2");
+}
+
+#[test]
+fn trim_whitespace() {
+    let trim = ParseOptions::new().trim_whitespace(true);
+    assert_eq!(absolute(trim, "\t1.3.6 \n").unwrap().to_string(), "1.3.6");
+    // positions point into the untrimmed text
+    assert!(matches!(
+        absolute(trim, "  1.03").err().unwrap(),
+        OidParsingError::LeadingZero(4)
+    ));
+    assert!(matches!(
+        absolute(trim, "  1. 3").err().unwrap(),
+        OidParsingError::InvalidChar(4)
+    ));
+    assert!(absolute(trim, "   ").is_err());
+}
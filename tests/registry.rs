@@ -0,0 +1,35 @@
+#![cfg(feature = "registry")]
+
+use oid_str::registry;
+
+use crate::common::oid;
+
+#[test]
+fn all_entries_round_trip() {
+    let mut count = 0;
+    for (name, oid) in registry::entries() {
+        assert_eq!(registry::name_of(oid), Some(name));
+        assert_eq!(registry::by_name(name), Some(oid));
+        count += 1;
+    }
+    assert!(count > 150);
+}
+
+#[test]
+fn known() {
+    assert_eq!(registry::name_of(&oid("1.2.840.113549.1.1.11")), Some("sha256WithRSAEncryption"));
+    assert_eq!(registry::name_of(&oid("1.3.6.1.2.1")), Some("mib-2"));
+    assert_eq!(registry::name_of(&registry::SECP256R1), Some("secp256r1"));
+    assert_eq!(registry::by_name("id-kp-serverAuth").unwrap().to_string(), "1.3.6.1.5.5.7.3.1");
+    assert_eq!(registry::by_name("id-at-commonName"), Some(&*registry::ID_AT_COMMON_NAME));
+}
+
+#[test]
+fn unknown() {
+    assert_eq!(registry::name_of(&oid("1.2.840.113549.1.1.99")), None);
+    assert_eq!(registry::name_of(&oid("2.999")), None);
+    assert_eq!(registry::name_of(&oid("1.3.6.1.2.1.1.1.0")), None);
+    assert_eq!(registry::by_name("id-ce-subjectaltname"), None);
+    assert_eq!(registry::by_name(""), None);
+    assert_eq!(registry::by_name("2.5.29.17"), None);
+}
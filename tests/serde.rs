@@ -0,0 +1,98 @@
+#![cfg(feature = "serde")]
+
+use oid_str::{
+    AbsoluteOid, AbsoluteOidVec, RelativeOid, RelativeOidVec, RootOid, SmallAbsoluteOid, SmallRelativeOid,
+};
+use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Token};
+
+fn absolute(s: &str) -> AbsoluteOidVec {
+    s.parse().unwrap()
+}
+
+#[test]
+fn absolute_readable() {
+    assert_tokens(&absolute("1.3.6.1").readable(), &[Token::Str("1.3.6.1")]);
+    assert_de_tokens(&absolute("1.3.6.1").readable(), &[Token::String(".1.3.6.1")]);
+}
+
+#[test]
+fn absolute_compact() {
+    assert_tokens(&absolute("1.3.6.1").compact(), &[Token::Bytes(b"\x2b\x06\x01")]);
+    assert_de_tokens(
+        &absolute("2.999").compact(),
+        &[Token::Seq { len: Some(2) }, Token::U8(0x88), Token::U8(0x37), Token::SeqEnd],
+    );
+}
+
+#[test]
+fn relative_readable_and_compact() {
+    let oid: RelativeOidVec = ".840.1".parse().unwrap();
+    assert_tokens(&oid.clone().readable(), &[Token::Str(".840.1")]);
+    assert_tokens(&oid.compact(), &[Token::Bytes(b"\x86\x48\x01")]);
+    assert_tokens(&RelativeOidVec::default().readable(), &[Token::Str("")]);
+}
+
+#[test]
+fn root() {
+    let root = RootOid::from_u8(0x2b).unwrap();
+    assert_tokens(&root.readable(), &[Token::Str("1.3")]);
+    assert_tokens(&root.compact(), &[Token::Bytes(b"\x2b")]);
+    assert_de_tokens_error::<serde_test::Readable<RootOid>>(
+        &[Token::Str("1.3.6")],
+        "expected only the first two arcs",
+    );
+    assert_de_tokens_error::<serde_test::Compact<RootOid>>(
+        &[Token::Bytes(b"\x2b\x06")],
+        "expected only the first two arcs",
+    );
+}
+
+#[test]
+fn invalid_input_is_rejected() {
+    assert_de_tokens_error::<serde_test::Compact<AbsoluteOidVec>>(
+        &[Token::Bytes(b"\x2b\x86")],
+        "invalid arc at byte 1",
+    );
+    assert_de_tokens_error::<serde_test::Readable<AbsoluteOidVec>>(
+        &[Token::Str("1.x")],
+        "invalid character at position 2",
+    );
+}
+
+#[test]
+fn borrowed() {
+    let bytes = b"\x2b\x06\x01";
+    let oid = AbsoluteOid::from_bytes(bytes).unwrap();
+    assert_de_tokens(&oid, &[Token::BorrowedBytes(bytes)]);
+
+    let oid = RelativeOid::from_bytes(b"\x06\x01").unwrap();
+    assert_de_tokens(&oid, &[Token::BorrowedBytes(b"\x06\x01")]);
+
+    assert_de_tokens_error::<&AbsoluteOid>(
+        &[Token::Bytes(bytes)],
+        "invalid type: byte array, expected borrowed BER-encoded absolute oid",
+    );
+}
+
+#[test]
+fn json_roundtrip() {
+    let oids = vec![absolute("1.2.840.113549.1.1.11"), absolute("2.999.1")];
+    let json = serde_json::to_string(&oids).unwrap();
+    assert_eq!(json, r#"["1.2.840.113549.1.1.11","2.999.1"]"#);
+    let decoded: Vec<AbsoluteOidVec> = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, oids);
+}
+
+#[test]
+fn small_readable_and_compact() {
+    let oid: SmallAbsoluteOid = "1.3.6.1".parse().unwrap();
+    assert_tokens(&oid.clone().readable(), &[Token::Str("1.3.6.1")]);
+    assert_tokens(&oid.compact(), &[Token::Bytes(b"\x2b\x06\x01")]);
+    let oid: SmallRelativeOid = ".840.1".parse().unwrap();
+    assert_tokens(&oid.clone().readable(), &[Token::Str(".840.1")]);
+    assert_tokens(&oid.compact(), &[Token::Bytes(b"\x86\x48\x01")]);
+
+    let json = r#"["1.2.840.113549.1.1.11","2.999.1"]"#;
+    let decoded: Vec<SmallAbsoluteOid> = serde_json::from_str(json).unwrap();
+    assert_eq!(serde_json::to_string(&decoded).unwrap(), json);
+}
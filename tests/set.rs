@@ -0,0 +1,73 @@
+use oid_str::{oid, AbsoluteOidVec, OidSet, StaticAbsoluteOid};
+
+use crate::common::oid;
+
+fn set(oids: &[&str]) -> OidSet {
+    oids.iter().map(|s| s.parse::<AbsoluteOidVec>().unwrap()).collect()
+}
+
+fn members(set: &OidSet) -> Vec<String> {
+    set.iter().map(|oid| oid.to_string()).collect()
+}
+
+#[test]
+fn insert_contains_remove() {
+    let mut set = OidSet::new();
+    assert!(set.insert(&oid("1.3.6.1")));
+    assert!(!set.insert(&oid("1.3.6.1")));
+    assert!(set.contains(&oid("1.3.6.1")));
+    assert!(!set.contains(&oid("1.3.6")));
+    assert!(set.remove(&oid("1.3.6.1")));
+    assert!(!set.remove(&oid("1.3.6.1")));
+    assert!(set.is_empty());
+}
+
+#[test]
+fn covers() {
+    let set = set(&["1.3.6.1.5.5.7", "1.2.840.113549.1.1", "2.999"]);
+    assert!(set.covers(&oid("1.3.6.1.5.5.7.3.1")));
+    assert!(set.covers(&oid("1.3.6.1.5.5.7")));
+    assert!(set.covers(&oid("2.999.1.2")));
+    assert!(!set.covers(&oid("1.3.6.1.5.5")));
+    assert!(!set.covers(&oid("1.3.6.1.5.5.70")));
+    assert_eq!(set.covering(&oid("1.2.840.113549.1.1.11")).unwrap().to_string(), "1.2.840.113549.1.1");
+}
+
+#[test]
+fn static_members() {
+    const PKIX: StaticAbsoluteOid<'static> = oid!("1.3.6.1.5.5.7");
+    let mut set = OidSet::new();
+    set.insert(&PKIX);
+    set.extend(vec![oid!("2.5.29")]);
+    assert!(set.contains(&PKIX));
+    assert!(set.covers(&oid!("2.5.29.15")));
+}
+
+#[test]
+fn minimize() {
+    let mut set = set(&[
+        "1.3.6.1",
+        "1.3.6.1.2.1",
+        "1.3.6.1.4.1.311",
+        "1.3.6.10",
+        "1.3.6.10.1",
+        "2.999.1",
+        "2.999.10",
+    ]);
+    let covered = ["1.3.6.1.2.1.1", "1.3.6.10.1.5", "2.999.10.1", "1.3.6.2"];
+    let before: Vec<bool> = covered.iter().map(|s| set.covers(&oid(s))).collect();
+    set.minimize();
+    assert_eq!(members(&set), ["1.3.6.1", "1.3.6.10", "2.999.1", "2.999.10"]);
+    let after: Vec<bool> = covered.iter().map(|s| set.covers(&oid(s))).collect();
+    assert_eq!(before, after);
+}
+
+#[test]
+fn set_algebra() {
+    let a = set(&["1.3", "1.3.6", "2.5.29"]);
+    let b = set(&["1.3.6", "2.5.29.15", "2.999"]);
+    assert_eq!(members(&a.union(&b)), ["1.3", "1.3.6", "2.5.29", "2.5.29.15", "2.999"]);
+    assert_eq!(members(&a.intersection(&b)), ["1.3.6"]);
+    assert_eq!(members(&a.difference(&b)), ["1.3", "2.5.29"]);
+    assert_eq!(members(&b.difference(&a)), ["2.5.29.15", "2.999"]);
+}
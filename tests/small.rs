@@ -0,0 +1,125 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeSet, HashMap};
+use std::hash::{Hash, Hasher};
+
+use oid_str::{
+    AbsoluteOid, AbsoluteOidVec, OidParsingError, RelativeOid, RelativeOidVec, SmallAbsoluteOid, SmallRelativeOid,
+    SMALL_OID_INLINE_LEN,
+};
+
+fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn spills_to_heap() {
+    let mut oid: SmallAbsoluteOid = "1.3.6.1.4.1".parse().unwrap();
+    let mut vec: AbsoluteOidVec = "1.3.6.1.4.1".parse().unwrap();
+    assert!(oid.is_inline());
+    while oid.len() <= SMALL_OID_INLINE_LEN {
+        assert!(oid.is_inline());
+        oid.push(300);
+        vec.push(300);
+    }
+    assert!(!oid.is_inline());
+    oid.extend(RelativeOid::from_bytes(b"\x01").unwrap());
+    vec.extend(RelativeOid::from_bytes(b"\x01").unwrap());
+    assert_eq!(oid.as_oid(), vec.as_oid());
+    assert_eq!(oid.into_bytes(), vec.into_bytes());
+}
+
+#[test]
+fn relative() {
+    let mut oid = SmallRelativeOid::default();
+    assert!(oid.is_empty());
+    for _ in 0..SMALL_OID_INLINE_LEN {
+        oid.push(1);
+    }
+    assert!(oid.is_inline());
+    oid.push(1);
+    assert!(!oid.is_inline());
+    assert_eq!(oid.len(), SMALL_OID_INLINE_LEN + 1);
+
+    let parsed: SmallRelativeOid = ".2.999".parse().unwrap();
+    assert_eq!(parsed.to_string(), ".2.999");
+    assert_eq!(RelativeOidVec::from(parsed.clone()).as_oid(), parsed.as_oid());
+}
+
+#[test]
+fn eq_and_hash_ignore_storage() {
+    let vec: AbsoluteOidVec = "1.2.840.113549".parse().unwrap();
+    let inline = SmallAbsoluteOid::from_oid(&vec);
+    let heap = SmallAbsoluteOid::from(vec.clone());
+    assert!(inline.is_inline());
+    assert!(!heap.is_inline());
+    assert_eq!(inline, heap);
+    assert_eq!(hash(&inline), hash(&heap));
+    assert_eq!(hash(&inline), hash(vec.as_oid()));
+
+    // lookups by the borrowed type
+    let mut map = HashMap::new();
+    map.insert(heap, "rsadsi");
+    assert_eq!(map.get(vec.as_oid()), Some(&"rsadsi"));
+
+    let set: BTreeSet<SmallAbsoluteOid> = ["1.3.6.1.10", "1.3.6.1.2", "2.999"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let sorted: Vec<String> = set.iter().map(|oid| oid.to_string()).collect();
+    assert_eq!(sorted, ["1.3.6.1.2", "1.3.6.1.10", "2.999"]);
+    assert!(set.contains(AbsoluteOid::from_bytes(b"\x88\x37").unwrap()));
+}
+
+#[test]
+fn conversions() {
+    let vec: AbsoluteOidVec = "2.5.29.19".parse().unwrap();
+    let small = SmallAbsoluteOid::from(vec.as_oid());
+    let owned: AbsoluteOidVec = small.as_oid().to_owned();
+    assert_eq!(owned, vec);
+    assert_eq!(AbsoluteOidVec::from(small), vec);
+
+    let root = vec.root();
+    assert_eq!(SmallAbsoluteOid::from_root(root).to_string(), "2.5");
+}
+
+#[test]
+fn parse_errors() {
+    assert!(matches!(
+        "1.3.06".parse::<SmallAbsoluteOid>().unwrap_err(),
+        OidParsingError::LeadingZero(4)
+    ));
+    assert!(matches!(
+        "1.x".parse::<SmallRelativeOid>().unwrap_err(),
+        OidParsingError::InvalidChar(2)
+    ));
+}
+
+#[test]
+fn from_urn() {
+    let oid = SmallAbsoluteOid::from_urn("urn:oid:1.3.6.1").unwrap();
+    assert_eq!(oid.to_string(), "1.3.6.1");
+    assert!(matches!(
+        SmallAbsoluteOid::from_urn("urn:oid:1.03").unwrap_err(),
+        OidParsingError::LeadingZero(10)
+    ));
+}
+
+#[test]
+fn from_ber_lenient() {
+    let (oid, normalized) = SmallRelativeOid::from_ber_lenient(b"\x80\x86\x48\x01").unwrap();
+    assert!(normalized);
+    assert_eq!(oid.to_string(), ".840.1");
+    assert!(SmallRelativeOid::from_ber_lenient(b"\x80\x80").is_err());
+
+    // longer than the inline buffer, but fits after the padding is removed
+    let mut padded = vec![0x2b];
+    padded.extend([0x80; SMALL_OID_INLINE_LEN]);
+    padded.push(0x01);
+    let (oid, normalized) = SmallAbsoluteOid::from_ber_lenient(&padded).unwrap();
+    assert!(normalized);
+    assert!(oid.is_inline());
+    assert_eq!(oid.to_string(), "1.3.1");
+    assert!(SmallAbsoluteOid::from_ber_lenient(b"").is_err());
+}
@@ -0,0 +1,160 @@
+use oid_str::{
+    AbsoluteOid, AbsoluteOidVec, EncodingRules, OidDecodingError, RelativeOid, Tag, TlvError,
+};
+
+#[test]
+fn decode_absolute_der() {
+    let (oid, rest) = AbsoluteOid::from_der_tlv(b"\x06\x03\x2b\x06\x01").unwrap();
+    assert_eq!(oid.to_string(), "1.3.6.1");
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn decode_absolute_der_with_rest() {
+    let (oid, rest) = AbsoluteOid::from_der_tlv(b"\x06\x01\x2b\x05\x00").unwrap();
+    assert_eq!(oid.to_string(), "1.3");
+    assert_eq!(rest, b"\x05\x00");
+}
+
+#[test]
+fn decode_relative_der() {
+    let (oid, rest) = RelativeOid::from_der_tlv(b"\x0d\x03\x86\x48\x01").unwrap();
+    assert_eq!(oid.to_string(), ".840.1");
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn decode_wrong_tag() {
+    let result = AbsoluteOid::from_der_tlv(b"\x0d\x01\x2b");
+    assert!(matches!(result, Err(TlvError::UnexpectedTag)), "result is {:?}", result);
+
+    let result = RelativeOid::from_der_tlv(b"\x06\x01\x2b");
+    assert!(matches!(result, Err(TlvError::UnexpectedTag)), "result is {:?}", result);
+}
+
+#[test]
+fn decode_constructed_is_rejected() {
+    let result = AbsoluteOid::from_der_tlv(b"\x26\x01\x2b");
+    assert!(matches!(result, Err(TlvError::UnexpectedTag)), "result is {:?}", result);
+}
+
+#[test]
+fn decode_truncated() {
+    for bytes in [&b""[..], b"\x06", b"\x06\x03\x2b\x06", b"\x06\x82\x01"] {
+        let result = AbsoluteOid::from_ber_tlv(bytes);
+        assert!(matches!(result, Err(TlvError::Truncated)), "result is {:?}", result);
+    }
+}
+
+#[test]
+fn decode_indefinite_length() {
+    let result = AbsoluteOid::from_ber_tlv(b"\x06\x80\x2b\x00\x00");
+    assert!(matches!(result, Err(TlvError::IndefiniteLength)), "result is {:?}", result);
+}
+
+#[test]
+fn decode_reserved_length() {
+    let result = AbsoluteOid::from_ber_tlv(b"\x06\xff\x2b");
+    assert!(matches!(result, Err(TlvError::InvalidLength)), "result is {:?}", result);
+}
+
+#[test]
+fn decode_length_overflow() {
+    let result = AbsoluteOid::from_ber_tlv(b"\x06\x89\x01\x00\x00\x00\x00\x00\x00\x00\x00");
+    assert!(matches!(result, Err(TlvError::InvalidLength)), "result is {:?}", result);
+}
+
+#[test]
+fn decode_long_form_length_ber() {
+    let (oid, rest) = AbsoluteOid::from_ber_tlv(b"\x06\x82\x00\x01\x2b").unwrap();
+    assert_eq!(oid.to_string(), "1.3");
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn decode_long_form_length_der_must_be_minimal() {
+    let result = AbsoluteOid::from_der_tlv(b"\x06\x81\x01\x2b");
+    assert!(matches!(result, Err(TlvError::NonMinimalLength)), "result is {:?}", result);
+
+    let result = AbsoluteOid::from_der_tlv(b"\x06\x82\x00\x01\x2b");
+    assert!(matches!(result, Err(TlvError::NonMinimalLength)), "result is {:?}", result);
+}
+
+#[test]
+fn decode_long_oid() {
+    let mut oid: AbsoluteOidVec = "1.3".parse().unwrap();
+    for _ in 0..200 {
+        oid.push(1);
+    }
+    let tlv = oid.to_der_tlv();
+    assert_eq!(&tlv[..3], b"\x06\x81\xc9");
+
+    let (decoded, rest) = AbsoluteOid::from_der_tlv(&tlv).unwrap();
+    assert_eq!(decoded, &*oid);
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn decode_invalid_content() {
+    let result = AbsoluteOid::from_der_tlv(b"\x06\x00");
+    assert!(
+        matches!(result, Err(TlvError::Content(OidDecodingError::Empty))),
+        "result is {:?}",
+        result
+    );
+
+    let result = RelativeOid::from_der_tlv(b"\x0d\x01\x80");
+    assert!(
+        matches!(result, Err(TlvError::Content(OidDecodingError::Base128(_)))),
+        "result is {:?}",
+        result
+    );
+}
+
+#[test]
+fn decode_implicit_tag() {
+    let tag = Tag::context_specific(0);
+    let (oid, _) = AbsoluteOid::from_tlv(b"\x80\x01\x2b", tag, EncodingRules::Der).unwrap();
+    assert_eq!(oid.to_string(), "1.3");
+
+    let result = AbsoluteOid::from_tlv(b"\x81\x01\x2b", tag, EncodingRules::Der);
+    assert!(matches!(result, Err(TlvError::UnexpectedTag)), "result is {:?}", result);
+}
+
+#[test]
+fn decode_high_tag_number() {
+    let tag = Tag::application(200);
+    let (oid, _) = RelativeOid::from_tlv(b"\x5f\x81\x48\x01\x01", tag, EncodingRules::Der).unwrap();
+    assert_eq!(oid.to_string(), ".1");
+}
+
+#[test]
+fn encode_absolute() {
+    let oid: AbsoluteOidVec = "1.2.840.113549".parse().unwrap();
+    assert_eq!(oid.der_tlv_len(), 8);
+    assert_eq!(oid.to_der_tlv(), b"\x06\x06\x2a\x86\x48\x86\xf7\x0d");
+}
+
+#[test]
+fn encode_relative_into_buffer() {
+    let oid = RelativeOid::from_bytes(b"\x86\x48\x01").unwrap();
+    let mut buf = [0; 8];
+    let len = oid.write_der_tlv(&mut buf).unwrap();
+    assert_eq!(&buf[..len], b"\x0d\x03\x86\x48\x01");
+}
+
+#[test]
+fn encode_buffer_too_small() {
+    let oid = AbsoluteOid::from_bytes(b"\x2b\x06\x01").unwrap();
+    let mut buf = [0; 4];
+    let err = oid.write_der_tlv(&mut buf).unwrap_err();
+    assert_eq!(err.required, 5);
+}
+
+#[test]
+fn encode_implicit_high_tag_number() {
+    let oid = RelativeOid::from_bytes(b"\x01").unwrap();
+    let tag = Tag::application(200);
+    assert_eq!(oid.tlv_len(tag), 5);
+    assert_eq!(oid.to_tlv(tag), b"\x5f\x81\x48\x01\x01");
+}
@@ -0,0 +1,110 @@
+use oid_str::{
+    AbsoluteOid, B128ErrorKind, OidDecodingError, RelativeOid, UnboundedAbsoluteOid,
+    UnboundedAbsoluteOidVec, UnboundedRelativeOid, UnboundedRelativeOidVec,
+};
+
+// 2^160
+const BIG_ARC_BYTES: &[u8] = b"\xc0\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x00";
+const BIG_ARC: &str = "1461501637330902918203684832716283019655932542976";
+
+#[test]
+fn unbounded_relative_accepts_large_arc() {
+    assert!(RelativeOid::from_bytes(BIG_ARC_BYTES).is_err());
+    let oid = UnboundedRelativeOid::from_bytes(BIG_ARC_BYTES).unwrap();
+    assert_eq!(oid.to_string(), format!(".{}", BIG_ARC));
+}
+
+#[test]
+fn unbounded_relative_rejects_zero_continuation() {
+    let err = UnboundedRelativeOid::from_bytes(b"\x01\x80\x01").unwrap_err();
+    assert!(matches!(err.kind, B128ErrorKind::ZeroByteWithCont), "kind is {:?}", err.kind);
+    assert_eq!(err.pos, 1);
+}
+
+#[test]
+fn unbounded_relative_rejects_unfinished() {
+    let err = UnboundedRelativeOid::from_bytes(b"\x01\xff\xff").unwrap_err();
+    assert!(matches!(err.kind, B128ErrorKind::Unfinished), "kind is {:?}", err.kind);
+    assert_eq!(err.pos, 1);
+}
+
+#[test]
+fn unbounded_arcs() {
+    let mut bytes = b"\x01".to_vec();
+    bytes.extend_from_slice(BIG_ARC_BYTES);
+    bytes.extend_from_slice(b"\x81\x00");
+    let oid = UnboundedRelativeOid::from_bytes(&bytes).unwrap();
+
+    let arcs: Vec<_> = oid.arcs().collect();
+    assert_eq!(arcs.len(), 3);
+    assert_eq!(arcs[0].to_arc(), Some(1));
+    assert_eq!(arcs[1].as_bytes(), BIG_ARC_BYTES);
+    assert_eq!(arcs[1].to_arc(), None);
+    assert_eq!(arcs[1].to_string(), BIG_ARC);
+    assert_eq!(arcs[2].to_arc(), Some(128));
+}
+
+#[test]
+fn unbounded_long_arcs_roundtrip() {
+    // the second arc is longer than the stack buffer of printing
+    for digits in [200, 400] {
+        let text = format!(".1{}.7", "9".repeat(digits));
+        let oid: UnboundedRelativeOidVec = text.parse().unwrap();
+        assert_eq!(oid.to_string(), text);
+    }
+}
+
+#[test]
+fn unbounded_absolute_uuid() {
+    let text = "2.25.329800735698586629295641978511506172918";
+    let oid: UnboundedAbsoluteOidVec = text.parse().unwrap();
+    assert_eq!(oid.to_string(), text);
+    assert_eq!(oid.root().to_string(), "2.25");
+
+    let decoded = UnboundedAbsoluteOid::from_bytes(oid.as_bytes()).unwrap();
+    assert_eq!(decoded, &*oid);
+}
+
+#[test]
+fn unbounded_absolute_root_must_fit_arc() {
+    let err = UnboundedAbsoluteOid::from_bytes(BIG_ARC_BYTES).unwrap_err();
+    assert!(matches!(err, OidDecodingError::Root(_)), "error is {:?}", err);
+}
+
+#[test]
+fn unbounded_absolute_error_position() {
+    let err = UnboundedAbsoluteOid::from_bytes(b"\x2b\x06\x80\x01").unwrap_err();
+    match err {
+        OidDecodingError::Base128(err) => assert_eq!(err.pos, 2),
+        _ => panic!("error is {:?}", err),
+    }
+}
+
+#[test]
+fn parse_relative_roundtrip() {
+    let text = format!(".1.{}.0.{}", BIG_ARC, BIG_ARC);
+    let oid: UnboundedRelativeOidVec = text.parse().unwrap();
+    assert_eq!(oid.to_string(), text);
+    assert_eq!(&oid.as_bytes()[1..1 + BIG_ARC_BYTES.len()], BIG_ARC_BYTES);
+}
+
+#[test]
+fn parse_relative_leading_zeros() {
+    let oid: UnboundedRelativeOidVec = ".007.0".parse().unwrap();
+    assert_eq!(oid.as_bytes(), b"\x07\x00");
+}
+
+#[test]
+fn parse_relative_empty() {
+    let oid: UnboundedRelativeOidVec = "".parse().unwrap();
+    assert!(oid.as_bytes().is_empty());
+}
+
+#[test]
+fn bounded_conversions() {
+    let oid = AbsoluteOid::from_bytes(b"\x2b\x06\x01").unwrap();
+    let unbounded = oid.as_unbounded();
+    assert_eq!(unbounded.to_string(), "1.3.6.1");
+    assert_eq!(unbounded.as_bounded().unwrap(), oid);
+    assert!(UnboundedRelativeOid::from_bytes(BIG_ARC_BYTES).unwrap().as_bounded().is_err());
+}
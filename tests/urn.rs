@@ -0,0 +1,53 @@
+use oid_str::{parse_urn, AbsoluteOid, AbsoluteOidVec, OidParsingError};
+
+fn error(s: &str) -> OidParsingError {
+    AbsoluteOidVec::from_urn(s).unwrap_err()
+}
+
+#[test]
+fn format() {
+    let oid = AbsoluteOid::from_bytes(b"\x60\x86\x48\x01\x86\xf9\x5b").unwrap();
+    assert_eq!(oid.urn().to_string(), "urn:oid:2.16.840.1.113883");
+    assert_eq!(oid.to_urn(), "urn:oid:2.16.840.1.113883");
+    assert_eq!(format!("{}", AbsoluteOid::from_bytes(b"\x00").unwrap().urn()), "urn:oid:0.0");
+}
+
+#[test]
+fn parse() {
+    let oid = AbsoluteOidVec::from_urn("urn:oid:1.3.6.1.4.1").unwrap();
+    assert_eq!(oid.to_string(), "1.3.6.1.4.1");
+    assert_eq!(AbsoluteOidVec::from_urn("URN:OID:2.999").unwrap().to_string(), "2.999");
+    assert_eq!(AbsoluteOidVec::from_urn("Urn:Oid:0.0").unwrap().to_string(), "0.0");
+    assert_eq!(AbsoluteOidVec::from_urn(&oid.to_urn()).unwrap(), oid);
+
+    let (root, arcs) = parse_urn("urn:oid:1.2.840").unwrap();
+    assert_eq!(root.to_string(), "1.2");
+    assert_eq!(arcs.map(Result::unwrap).collect::<Vec<_>>(), [840]);
+}
+
+#[test]
+fn prefix_errors() {
+    assert!(matches!(error(""), OidParsingError::UrnPrefixExpected(0)));
+    assert!(matches!(error("1.3.6"), OidParsingError::UrnPrefixExpected(0)));
+    assert!(matches!(error("urn:oid"), OidParsingError::UrnPrefixExpected(7)));
+    assert!(matches!(error("urn:iod:1.3"), OidParsingError::UrnPrefixExpected(4)));
+    assert!(matches!(error("urn oid:1.3"), OidParsingError::UrnPrefixExpected(3)));
+}
+
+#[test]
+fn nss_errors() {
+    assert!(matches!(error("urn:oid:"), OidParsingError::IntegerExpected(8)));
+    assert!(matches!(error("urn:oid:.1.3"), OidParsingError::IntegerExpected(8)));
+    assert!(matches!(error("urn:oid:1..3"), OidParsingError::IntegerExpected(10)));
+    assert!(matches!(error("urn:oid:1.3."), OidParsingError::IntegerExpected(12)));
+    assert!(matches!(error("urn:oid:1.03"), OidParsingError::LeadingZero(10)));
+    assert!(matches!(error("urn:oid:1.3.x"), OidParsingError::InvalidChar(12)));
+    assert!(matches!(error("urn:oid:1"), OidParsingError::NoArc1));
+    assert!(matches!(error("urn:oid:3.1"), OidParsingError::InvalidArc0(r) if r == (8..9)));
+    assert_eq!(
+        error("urn:iod:1").to_string(),
+        "expected `urn:oid:` prefix, mismatch at position 4"
+    );
+    // zero itself is fine
+    assert_eq!(AbsoluteOidVec::from_urn("urn:oid:1.0.0").unwrap().to_string(), "1.0.0");
+}